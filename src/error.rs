@@ -139,3 +139,106 @@ impl CaptchaError {
         }
     }
 }
+#[derive(thiserror::Error, Debug)]
+pub enum EhallError {
+    #[error(transparent)]
+    AgentError(#[from] AgentError),
+    #[error("未登录或登录已失效。")]
+    NotLoggedIn,
+    #[error("响应解析失败：`{0}`.")]
+    ParseError(String),
+    #[error("服务器返回信息：`{0}`.")]
+    ServerError(String),
+    #[error("读写缓存失败：`{0}`.")]
+    IoError(#[from] std::io::Error),
+}
+impl From<ureq::Error> for EhallError {
+    #[inline]
+    fn from(value: ureq::Error) -> Self {
+        Self::AgentError(value.into())
+    }
+}
+impl From<Box<ureq::Error>> for EhallError {
+    #[inline]
+    fn from(value: Box<ureq::Error>) -> Self {
+        Self::AgentError(value.into())
+    }
+}
+impl EhallError {
+    #[inline]
+    pub fn is_fatal(&self) -> bool {
+        match self {
+            EhallError::AgentError(e) => e.is_fatal(),
+            EhallError::NotLoggedIn => true,
+            EhallError::ParseError(_) => true,
+            EhallError::ServerError(_) => false,
+            EhallError::IoError(_) => false,
+        }
+    }
+}
+#[derive(thiserror::Error, Debug)]
+pub enum PaymentError {
+    #[error(transparent)]
+    AgentError(#[from] AgentError),
+    #[error("未登录或登录已失效。")]
+    NotLoggedIn,
+    #[error("响应解析失败：`{0}`.")]
+    ParseError(String),
+    #[error("服务器返回信息：`{0}`.")]
+    ServerError(String),
+}
+impl From<ureq::Error> for PaymentError {
+    #[inline]
+    fn from(value: ureq::Error) -> Self {
+        Self::AgentError(value.into())
+    }
+}
+impl PaymentError {
+    #[inline]
+    pub fn is_fatal(&self) -> bool {
+        match self {
+            PaymentError::AgentError(e) => e.is_fatal(),
+            PaymentError::NotLoggedIn => true,
+            PaymentError::ParseError(_) => true,
+            PaymentError::ServerError(_) => false,
+        }
+    }
+}
+#[derive(thiserror::Error, Debug)]
+pub enum RSBBSError {
+    #[error(transparent)]
+    AgentError(#[from] AgentError),
+    #[error("未登录或登录已失效。")]
+    NotLoggedIn,
+    #[error("页面解析失败：`{0}`.")]
+    ParseError(String),
+    #[error("服务器返回信息：`{0}`.")]
+    ServerError(String),
+    /// 发表间隔过短，包含需要等待的秒数（若页面给出）。
+    #[error("发表间隔过短，请稍后再试。")]
+    FloodControl(Option<u32>),
+    #[error("没有权限：`{0}`.")]
+    PermissionDenied(String),
+    #[error("需要填写验证码。")]
+    SeccodeRequired,
+}
+impl From<ureq::Error> for RSBBSError {
+    #[inline]
+    fn from(value: ureq::Error) -> Self {
+        Self::AgentError(value.into())
+    }
+}
+impl RSBBSError {
+    #[inline]
+    pub fn is_fatal(&self) -> bool {
+        match self {
+            RSBBSError::AgentError(e) => e.is_fatal(),
+            RSBBSError::NotLoggedIn => true,
+            RSBBSError::ParseError(_) => true,
+            RSBBSError::ServerError(_) => false,
+            RSBBSError::FloodControl(_) => false,
+            RSBBSError::PermissionDenied(_) => true,
+            RSBBSError::SeccodeRequired => true,
+        }
+    }
+}
@@ -20,7 +20,7 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::protocol::ehall::EhallProtocolItem;
+use crate::{protocol::ehall::EhallProtocolItem, utils::percent_enc};
 use ureq::{Agent, Body, http::Response};
 #[inline]
 pub fn use_app(agent: &Agent, app_id: &str) -> Result<Response<Body>, Box<ureq::Error>> {
@@ -34,16 +34,51 @@ pub fn use_app(agent: &Agent, app_id: &str) -> Result<Response<Body>, Box<ureq::
 }
 
 #[inline]
-pub fn get_app_list(agent: &Agent, search_key: &str) -> Result<Response<Body>, Box<ureq::Error>> {
+pub fn get_app_list(
+    agent: &Agent,
+    search_key: &str,
+    page_number: u32,
+    page_size: u32,
+    sort_key: &str,
+    order_key: &str,
+) -> Result<Response<Body>, Box<ureq::Error>> {
     Ok(agent
         .get(&format!(
             "{}?{}&{}&{}&{}&{}",
             EhallProtocolItem::ServiceSearchCustom,
-            format_args!("searchKey={search_key}"),
-            "pageNumber=1",
-            "pageSize=150",
-            "sortKey=recentUseCount",
-            "orderKey=desc",
+            format_args!("searchKey={}", percent_enc(search_key)),
+            format_args!("pageNumber={page_number}"),
+            format_args!("pageSize={page_size}"),
+            format_args!("sortKey={sort_key}"),
+            format_args!("orderKey={order_key}"),
         ))
         .call()?)
 }
+
+#[inline]
+pub fn get_app_introduction(
+    agent: &Agent,
+    app_id: &str,
+) -> Result<Response<Body>, Box<ureq::Error>> {
+    Ok(agent
+        .get(&format!(
+            "{}?appId={}",
+            EhallProtocolItem::AppIntroduction,
+            percent_enc(app_id)
+        ))
+        .call()?)
+}
+/// 下载应用图标，`path` 可以是相对于 ehall 根目录的路径。
+#[inline]
+pub fn get_app_icon(agent: &Agent, path: &str) -> Result<Response<Body>, Box<ureq::Error>> {
+    let url = if path.starts_with("http://") || path.starts_with("https://") {
+        path.to_owned()
+    } else {
+        format!(
+            "{}/{}",
+            EhallProtocolItem::BASE_URL,
+            path.trim_start_matches('/')
+        )
+    };
+    Ok(agent.get(&url).call()?)
+}
@@ -0,0 +1,64 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{protocol::ehall::EhallProtocolItem, utils::percent_enc};
+use ureq::{Agent, Body, http::Response};
+
+#[inline]
+pub fn get_favorite_apps(agent: &Agent) -> Result<Response<Body>, Box<ureq::Error>> {
+    Ok(agent
+        .get(EhallProtocolItem::UserFavoriteApps.get())
+        .call()?)
+}
+#[inline]
+pub fn add_favorite_app(agent: &Agent, app_id: &str) -> Result<Response<Body>, Box<ureq::Error>> {
+    Ok(agent
+        .get(&format!(
+            "{}?appId={}",
+            EhallProtocolItem::AddFavoriteApp,
+            percent_enc(app_id)
+        ))
+        .call()?)
+}
+#[inline]
+pub fn remove_favorite_app(
+    agent: &Agent,
+    app_id: &str,
+) -> Result<Response<Body>, Box<ureq::Error>> {
+    Ok(agent
+        .get(&format!(
+            "{}?appId={}",
+            EhallProtocolItem::RemoveFavoriteApp,
+            percent_enc(app_id)
+        ))
+        .call()?)
+}
+/// `app_ids` 为排序后的全部收藏应用。
+#[inline]
+pub fn sort_favorite_apps(
+    agent: &Agent,
+    app_ids: &[&str],
+) -> Result<Response<Body>, Box<ureq::Error>> {
+    Ok(agent
+        .post(EhallProtocolItem::SortFavoriteApps.get())
+        .send_form([("appIds", app_ids.join(",").as_str())])?)
+}
@@ -20,25 +20,20 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::protocol::ehall::EhallProtocolItem;
+use crate::protocol::ehall::get_favorite_apps;
 use serde::Deserialize;
 use ureq::Agent;
 
 #[inline]
 pub fn has_logged_in(agent: &Agent) -> bool {
-    agent
-        .get(EhallProtocolItem::UserFavoriteApps.get())
-        .call()
-        .is_ok_and(|r| {
-            #[derive(Deserialize)]
-            struct Tmp {
-                #[serde(rename = "hasLogin")]
-                has_login: bool,
-            }
-            let Tmp { has_login } = r
-                .into_body()
-                .read_json()
-                .expect("failed to deserialize hasLogin");
-            has_login
-        })
+    get_favorite_apps(agent).is_ok_and(|r| {
+        #[derive(Deserialize)]
+        struct Tmp {
+            #[serde(rename = "hasLogin")]
+            has_login: bool,
+        }
+        r.into_body()
+            .read_json::<Tmp>()
+            .is_ok_and(|Tmp { has_login }| has_login)
+    })
 }
@@ -0,0 +1,60 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::protocol::ehall::EhallProtocolItem;
+use ureq::{Agent, Body, http::Response};
+
+#[inline]
+pub fn get_todo_tasks(
+    agent: &Agent,
+    page_number: u32,
+    page_size: u32,
+) -> Result<Response<Body>, Box<ureq::Error>> {
+    Ok(agent
+        .get(&format!(
+            "{}?pageNumber={page_number}&pageSize={page_size}",
+            EhallProtocolItem::TodoTasks
+        ))
+        .call()?)
+}
+#[inline]
+pub fn get_messages(
+    agent: &Agent,
+    page_number: u32,
+    page_size: u32,
+) -> Result<Response<Body>, Box<ureq::Error>> {
+    Ok(agent
+        .get(&format!(
+            "{}?pageNumber={page_number}&pageSize={page_size}",
+            EhallProtocolItem::Messages
+        ))
+        .call()?)
+}
+#[inline]
+pub fn read_messages(
+    agent: &Agent,
+    message_ids: &[&str],
+) -> Result<Response<Body>, Box<ureq::Error>> {
+    Ok(agent
+        .post(EhallProtocolItem::ReadMessages.get())
+        .send_form([("messageIds", message_ids.join(",").as_str())])?)
+}
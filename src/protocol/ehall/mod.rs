@@ -21,25 +21,55 @@
 // SOFTWARE.
 
 mod app;
+mod favorite;
 mod login;
+mod message;
+mod process;
+mod role;
 
 pub use app::*;
+pub use favorite::*;
 pub use login::*;
+pub use message::*;
+pub use process::*;
+pub use role::*;
 
 use std::fmt::Display;
 
 pub enum EhallProtocolItem {
     UserFavoriteApps,
+    AddFavoriteApp,
+    RemoveFavoriteApp,
+    SortFavoriteApps,
     AppShow,
     ServiceSearchCustom,
+    TodoTasks,
+    Messages,
+    ReadMessages,
+    MyApplications,
+    ApprovalHistory,
+    UserRoles,
+    SelectRole,
+    AppIntroduction,
 }
 impl EhallProtocolItem {
     #[inline]
     fn get_default(&self) -> &'static str {
         match self {
             EhallProtocolItem::UserFavoriteApps => Self::USER_FAVORITE_APPS,
+            EhallProtocolItem::AddFavoriteApp => Self::ADD_FAVORITE_APP,
+            EhallProtocolItem::RemoveFavoriteApp => Self::REMOVE_FAVORITE_APP,
+            EhallProtocolItem::SortFavoriteApps => Self::SORT_FAVORITE_APPS,
             EhallProtocolItem::AppShow => Self::APP_SHOW,
             EhallProtocolItem::ServiceSearchCustom => Self::SERVICE_SEARCH_CUSTOM,
+            EhallProtocolItem::TodoTasks => Self::TODO_TASKS,
+            EhallProtocolItem::Messages => Self::MESSAGES,
+            EhallProtocolItem::ReadMessages => Self::READ_MESSAGES,
+            EhallProtocolItem::MyApplications => Self::MY_APPLICATIONS,
+            EhallProtocolItem::ApprovalHistory => Self::APPROVAL_HISTORY,
+            EhallProtocolItem::UserRoles => Self::USER_ROLES,
+            EhallProtocolItem::SelectRole => Self::SELECT_ROLE,
+            EhallProtocolItem::AppIntroduction => Self::APP_INTRODUCTION,
         }
     }
 }
@@ -51,11 +81,30 @@ impl EhallProtocolItem {
     }
 }
 impl EhallProtocolItem {
+    pub const BASE_URL: &'static str = "http://ehall.xidian.edu.cn";
     pub const USER_FAVORITE_APPS: &'static str =
         "http://ehall.xidian.edu.cn/jsonp/userFavoriteApps.json";
+    pub const ADD_FAVORITE_APP: &'static str =
+        "http://ehall.xidian.edu.cn/jsonp/userFavoriteApps/add.json";
+    pub const REMOVE_FAVORITE_APP: &'static str =
+        "http://ehall.xidian.edu.cn/jsonp/userFavoriteApps/delete.json";
+    pub const SORT_FAVORITE_APPS: &'static str =
+        "http://ehall.xidian.edu.cn/jsonp/userFavoriteApps/sort.json";
     pub const APP_SHOW: &'static str = "http://ehall.xidian.edu.cn//appShow";
     pub const SERVICE_SEARCH_CUSTOM: &'static str =
         "http://ehall.xidian.edu.cn/jsonp/serviceSearchCustom.json";
+    pub const TODO_TASKS: &'static str = "http://ehall.xidian.edu.cn/jsonp/task/todoTasks.json";
+    pub const MESSAGES: &'static str = "http://ehall.xidian.edu.cn/jsonp/message/messages.json";
+    pub const READ_MESSAGES: &'static str =
+        "http://ehall.xidian.edu.cn/jsonp/message/readMessages.json";
+    pub const MY_APPLICATIONS: &'static str =
+        "http://ehall.xidian.edu.cn/jsonp/process/myApplications.json";
+    pub const APPROVAL_HISTORY: &'static str =
+        "http://ehall.xidian.edu.cn/jsonp/process/approvalHistory.json";
+    pub const USER_ROLES: &'static str = "http://ehall.xidian.edu.cn/jsonp/userRoles.json";
+    pub const SELECT_ROLE: &'static str = "http://ehall.xidian.edu.cn/jsonp/selectRole.json";
+    pub const APP_INTRODUCTION: &'static str =
+        "http://ehall.xidian.edu.cn/jsonp/appIntroduction.json";
 }
 impl Display for EhallProtocolItem {
     #[inline]
@@ -0,0 +1,50 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::protocol::ehall::EhallProtocolItem;
+use ureq::{Agent, Body, http::Response};
+
+#[inline]
+pub fn get_my_applications(
+    agent: &Agent,
+    page_number: u32,
+    page_size: u32,
+) -> Result<Response<Body>, Box<ureq::Error>> {
+    Ok(agent
+        .get(&format!(
+            "{}?pageNumber={page_number}&pageSize={page_size}",
+            EhallProtocolItem::MyApplications
+        ))
+        .call()?)
+}
+#[inline]
+pub fn get_approval_history(
+    agent: &Agent,
+    instance_id: &str,
+) -> Result<Response<Body>, Box<ureq::Error>> {
+    Ok(agent
+        .get(&format!(
+            "{}?instanceId={instance_id}",
+            EhallProtocolItem::ApprovalHistory
+        ))
+        .call()?)
+}
@@ -21,19 +21,23 @@
 // SOFTWARE.
 
 #![cfg(feature = "ids")]
-use crate::{error::AgentError, protocol::ids::IDSProtocolItem};
+use crate::{
+    error::AgentError,
+    protocol::ids::{CasProfile, IDSProtocolItem},
+};
 use ureq::{Agent, Body, http::Response};
 
 #[inline]
 pub fn check_need_captcha(
     agent: &Agent,
+    profile: &CasProfile,
     uname: &str,
     time_stamp_mills: u128,
 ) -> Result<Response<Body>, AgentError> {
     Ok(agent
         .get(&format!(
             "{}?username={}&_={}",
-            IDSProtocolItem::CheckNeedCaptcha,
+            IDSProtocolItem::CheckNeedCaptcha.url(profile),
             uname,
             time_stamp_mills
         ))
@@ -43,25 +47,27 @@ pub fn check_need_captcha(
 #[inline]
 pub fn open_slider_captcha(
     agent: &Agent,
+    profile: &CasProfile,
     time_stamp_mills: u128,
 ) -> Result<Response<Body>, AgentError> {
     Ok(agent
         .get(&format!(
             "{}?_={time_stamp_mills}",
-            IDSProtocolItem::OpenSliderCaptcha
+            IDSProtocolItem::OpenSliderCaptcha.url(profile)
         ))
         .call()?)
 }
 #[inline]
 pub fn verify_slider_captcha(
     agent: &Agent,
+    profile: &CasProfile,
     move_length: u32,
 ) -> Result<Response<Body>, AgentError> {
     Ok(agent
-        .post(IDSProtocolItem::VerifySliderCaptcha.get())
-        .header("Refer", "https://ids.xidian.edu.cn/authserver/login")
+        .post(&IDSProtocolItem::VerifySliderCaptcha.url(profile))
+        .header("Refer", &IDSProtocolItem::Login.url(profile))
         .send_form([
-            ("canvasLength", "280"),
+            ("canvasLength", profile.canvas_width.to_string().as_str()),
             ("moveLength", move_length.to_string().as_str()),
         ])?)
 }
@@ -21,15 +21,26 @@
 // SOFTWARE.
 
 #![cfg(feature = "ids")]
-use crate::{error::AgentError, protocol::ids::IDSProtocolItem, utils::percent_enc};
+use crate::{
+    error::AgentError,
+    protocol::ids::{CasProfile, IDSProtocolItem},
+    utils::percent_enc,
+};
 use log::debug;
 use ureq::{Agent, Body, http::Response};
 
 #[inline]
-pub fn login_page(agent: &Agent, target: &str) -> Result<Response<Body>, AgentError> {
+pub fn login_page(
+    agent: &Agent,
+    profile: &CasProfile,
+    target: &str,
+) -> Result<Response<Body>, AgentError> {
     let target = percent_enc(target);
     Ok(agent
-        .get(&format!("{}?service={target}", IDSProtocolItem::Login))
+        .get(&format!(
+            "{}?service={target}",
+            IDSProtocolItem::Login.url(profile)
+        ))
         .call()?)
 }
 
@@ -49,18 +60,23 @@ pub fn login_page(agent: &Agent, target: &str) -> Result<Response<Body>, AgentEr
 #[inline]
 pub fn login(
     agent: &Agent,
+    profile: &CasProfile,
     target: &str,
     data: Vec<(&str, &str)>,
 ) -> Result<Response<Body>, AgentError> {
     let target = percent_enc(target);
     Ok(agent
-        .post(&format!("{}?service={}", IDSProtocolItem::Login, target))
+        .post(&format!(
+            "{}?service={}",
+            IDSProtocolItem::Login.url(profile),
+            target
+        ))
         .send_form(data)?)
 }
 #[inline]
-pub fn has_logged_in(agent: &Agent) -> bool {
+pub fn has_logged_in(agent: &Agent, profile: &CasProfile) -> bool {
     agent
-        .get(IDSProtocolItem::Authserver.get())
+        .get(&IDSProtocolItem::Authserver.url(profile))
         .config()
         .max_redirects(0)
         .build()
@@ -74,14 +90,14 @@ pub fn has_logged_in(agent: &Agent) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use crate::protocol::ids::has_logged_in;
+    use crate::protocol::ids::{CasProfile, has_logged_in};
     use log::info;
     use ureq::Agent;
 
     #[test]
     fn test_authserver() {
         let agent = Agent::new_with_config(Agent::config_builder().max_redirects(0).build());
-        let r = has_logged_in(&agent);
+        let r = has_logged_in(&agent, &CasProfile::XIDIAN);
         info!("{r}");
     }
 }
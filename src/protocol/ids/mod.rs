@@ -22,10 +22,12 @@
 
 mod captcha;
 mod login;
+mod profile;
 mod user;
 
 pub use captcha::*;
 pub use login::*;
+pub use profile::*;
 pub use user::*;
 
 use std::fmt::Display;
@@ -56,16 +58,32 @@ impl IDSProtocolItem {
     pub fn get(&self) -> &'static str {
         self.get_default()
     }
+    #[inline]
+    pub fn path<'a>(&self, profile: &'a CasProfile) -> &'a str {
+        match self {
+            IDSProtocolItem::Login => &profile.login_path,
+            IDSProtocolItem::CheckNeedCaptcha => &profile.check_need_captcha_path,
+            IDSProtocolItem::VerifySliderCaptcha => &profile.verify_slider_captcha_path,
+            IDSProtocolItem::OpenSliderCaptcha => &profile.open_slider_captcha_path,
+            IDSProtocolItem::Authserver => &profile.authserver_path,
+            IDSProtocolItem::GetUserConf => &profile.get_user_conf_path,
+        }
+    }
+    /// 给定部署下该接口的完整地址。
+    #[inline]
+    pub fn url(&self, profile: &CasProfile) -> String {
+        format!("{}{}", profile.base_url, self.path(profile))
+    }
 }
 impl IDSProtocolItem {
-    pub const LOGIN: &'static str = "http://ids.xidian.edu.cn/authserver/login";
+    pub const LOGIN: &'static str = "https://ids.xidian.edu.cn/authserver/login";
     pub const CHECK_NEED_CAPTCHA: &'static str =
         "https://ids.xidian.edu.cn/authserver/checkNeedCaptcha.htl";
     pub const VERIFY_SLIDER_CAPTCHA: &'static str =
         "https://ids.xidian.edu.cn/authserver/common/verifySliderCaptcha.htl";
     pub const OPEN_SLIDER_CAPTCHA: &'static str =
         "https://ids.xidian.edu.cn/authserver/common/openSliderCaptcha.htl";
-    pub const AUTHSERVER: &'static str = "http://ids.xidian.edu.cn/authserver/index.do";
+    pub const AUTHSERVER: &'static str = "https://ids.xidian.edu.cn/authserver/index.do";
 
     pub const GET_USER_CONF: &'static str =
         "https://ids.xidian.edu.cn/personalInfo/common/getUserConf";
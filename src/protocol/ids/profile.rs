@@ -0,0 +1,119 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// 金智（Wisedu）统一身份认证（CAS authserver）的部署描述。
+///
+/// 不同学校部署的 authserver 大同小异，仅主机、路径、滑块画布宽度与登录表单的 id 不同。
+/// 默认为西电的部署，其他部署可以从配置文件中反序列化得到，未给出的字段沿用西电的值：
+///
+/// ``` json
+/// {
+///     "base_url": "https://authserver.example.edu.cn"
+/// }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CasProfile {
+    /// 协议与主机，不含末尾的 `/`, 如 `https://ids.xidian.edu.cn`.
+    pub base_url: Cow<'static, str>,
+    pub login_path: Cow<'static, str>,
+    pub authserver_path: Cow<'static, str>,
+    pub check_need_captcha_path: Cow<'static, str>,
+    pub open_slider_captcha_path: Cow<'static, str>,
+    pub verify_slider_captcha_path: Cow<'static, str>,
+    pub get_user_conf_path: Cow<'static, str>,
+    /// 滑块验证码在网页上的画布宽度，提交的滑动距离需按此宽度缩放。
+    pub canvas_width: u32,
+    /// 密码登录表单所在容器的 id.
+    pub pwd_login_div_id: Cow<'static, str>,
+    /// 密码登录表单的 id.
+    pub pwd_form_id: Cow<'static, str>,
+}
+impl CasProfile {
+    pub const XIDIAN: Self = Self {
+        base_url: Cow::Borrowed("https://ids.xidian.edu.cn"),
+        login_path: Cow::Borrowed("/authserver/login"),
+        authserver_path: Cow::Borrowed("/authserver/index.do"),
+        check_need_captcha_path: Cow::Borrowed("/authserver/checkNeedCaptcha.htl"),
+        open_slider_captcha_path: Cow::Borrowed("/authserver/common/openSliderCaptcha.htl"),
+        verify_slider_captcha_path: Cow::Borrowed("/authserver/common/verifySliderCaptcha.htl"),
+        get_user_conf_path: Cow::Borrowed("/personalInfo/common/getUserConf"),
+        canvas_width: 280,
+        pwd_login_div_id: Cow::Borrowed("pwdLoginDiv"),
+        pwd_form_id: Cow::Borrowed("pwdFromId"),
+    };
+    /// 仅替换主机，其余沿用西电的部署。
+    #[inline]
+    pub fn with_base_url(base_url: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            ..Self::XIDIAN
+        }
+    }
+    /// 登录页面中用于定位表单的标识。
+    #[inline]
+    pub fn form_idents(&self) -> [String; 2] {
+        [
+            format!("id=\"{}\"", self.pwd_login_div_id),
+            format!("id=\"{}\"", self.pwd_form_id),
+        ]
+    }
+}
+impl Default for CasProfile {
+    #[inline]
+    fn default() -> Self {
+        Self::XIDIAN
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::ids::IDSProtocolItem;
+
+    #[test]
+    fn test_xidian_profile() {
+        let profile = CasProfile::default();
+        assert_eq!(IDSProtocolItem::Login.url(&profile), IDSProtocolItem::LOGIN);
+        assert_eq!(
+            IDSProtocolItem::VerifySliderCaptcha.url(&profile),
+            IDSProtocolItem::VERIFY_SLIDER_CAPTCHA
+        );
+    }
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_profile_from_json() {
+        let profile: CasProfile = serde_json::from_str(
+            r#"{"base_url":"https://authserver.example.edu.cn","canvas_width":300}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            IDSProtocolItem::Authserver.url(&profile),
+            "https://authserver.example.edu.cn/authserver/index.do"
+        );
+        assert_eq!(profile.canvas_width, 300);
+        assert_eq!(profile.pwd_form_id, "pwdFromId");
+    }
+}
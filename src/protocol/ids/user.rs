@@ -21,7 +21,7 @@
 // SOFTWARE.
 
 #![cfg(feature = "ids")]
-use crate::protocol::ids::IDSProtocolItem;
+use crate::protocol::ids::{CasProfile, IDSProtocolItem};
 use serde::Serialize;
 use ureq::{Agent, Body, http::Response};
 
@@ -32,13 +32,16 @@ use ureq::{Agent, Body, http::Response};
 /// }
 /// ```
 #[inline]
-pub fn get_user_conf(agent: &Agent) -> Result<Response<Body>, Box<ureq::Error>> {
+pub fn get_user_conf(
+    agent: &Agent,
+    profile: &CasProfile,
+) -> Result<Response<Body>, Box<ureq::Error>> {
     #[derive(Serialize)]
     struct Data {
         n: f64,
     }
     Ok(agent
-        .post(IDSProtocolItem::GetUserConf.get())
+        .post(&IDSProtocolItem::GetUserConf.url(profile))
         .send_json(Data {
             n: 0.12724911253015814, // 似乎没用。
         })?)
@@ -50,7 +53,7 @@ mod tests {
     use log::info;
     #[test]
     fn test_get_user_conf() {
-        let r = get_user_conf(&Agent::new_with_defaults()).unwrap();
+        let r = get_user_conf(&Agent::new_with_defaults(), &CasProfile::XIDIAN).unwrap();
         info!("{}", r.into_body().read_to_string().unwrap());
     }
 }
@@ -24,5 +24,7 @@
 pub mod ehall;
 #[cfg(feature = "ids")]
 pub mod ids;
+#[cfg(feature = "payment")]
+pub mod payment;
 #[cfg(feature = "rsbbs")]
 pub mod rsbbs;
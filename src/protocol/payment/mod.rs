@@ -0,0 +1,84 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    error::AgentError,
+    protocol::ids::{CasProfile, login_page},
+};
+use std::fmt::Display;
+use ureq::{Agent, Body, http::Response};
+
+pub enum PaymentProtocolItem {
+    SsoService,
+    Index,
+    FeeItems,
+}
+impl PaymentProtocolItem {
+    #[inline]
+    fn get_default(&self) -> &'static str {
+        match self {
+            PaymentProtocolItem::SsoService => Self::SSO_SERVICE,
+            PaymentProtocolItem::Index => Self::INDEX,
+            PaymentProtocolItem::FeeItems => Self::FEE_ITEMS,
+        }
+    }
+}
+impl PaymentProtocolItem {
+    #[inline]
+    pub fn get(&self) -> &'static str {
+        self.get_default()
+    }
+}
+impl PaymentProtocolItem {
+    pub const SSO_SERVICE: &'static str = "https://payment.xidian.edu.cn/NetWorkUI/ssoLogin";
+    pub const INDEX: &'static str = "https://payment.xidian.edu.cn/NetWorkUI/index";
+    pub const FEE_ITEMS: &'static str = "https://payment.xidian.edu.cn/NetWorkUI/queryFeeItems";
+}
+impl Display for PaymentProtocolItem {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        self.get().fmt(f)
+    }
+}
+
+/// 已登录统一身份认证时，访问认证页会直接携带 ticket 跳转回缴费平台。
+#[inline]
+pub fn sso_login(agent: &Agent, profile: &CasProfile) -> Result<Response<Body>, AgentError> {
+    login_page(agent, profile, PaymentProtocolItem::SsoService.get())
+}
+/// `year` 为空时查询所有学年。
+#[inline]
+pub fn get_fee_items(agent: &Agent, year: &str) -> Result<Response<Body>, AgentError> {
+    Ok(agent
+        .post(PaymentProtocolItem::FeeItems.get())
+        .send_form([("xn", year)])?)
+}
+#[inline]
+pub fn has_logged_in(agent: &Agent) -> bool {
+    agent
+        .get(PaymentProtocolItem::Index.get())
+        .config()
+        .max_redirects(0)
+        .build()
+        .call()
+        .is_ok_and(|r| r.status() != 302)
+}
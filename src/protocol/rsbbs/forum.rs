@@ -0,0 +1,54 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{error::AgentError, protocol::rsbbs::RSBBSProtocolItem};
+use ureq::{Agent, Body, http::Response};
+
+/// 论坛首页，包含所有分区与版块。
+#[inline]
+pub fn forum_index(agent: &Agent) -> Result<Response<Body>, AgentError> {
+    let url = format!("https://{}/forum.php", RSBBSProtocolItem::Host);
+    Ok(agent.get(&url).call()?)
+}
+/// 版块的主题列表，`extra` 为附加的查询参数，如 `filter=typeid&typeid=1`.
+#[inline]
+pub fn forum_display(
+    agent: &Agent,
+    fid: u64,
+    page: u32,
+    extra: &str,
+) -> Result<Response<Body>, AgentError> {
+    let url = format!(
+        "https://{}/forum.php?mod=forumdisplay&fid={fid}&page={page}&{extra}",
+        RSBBSProtocolItem::Host
+    );
+    Ok(agent.get(&url).call()?)
+}
+/// 主题的某一页。
+#[inline]
+pub fn view_thread(agent: &Agent, tid: u64, page: u32) -> Result<Response<Body>, AgentError> {
+    let url = format!(
+        "https://{}/forum.php?mod=viewthread&tid={tid}&page={page}",
+        RSBBSProtocolItem::Host
+    );
+    Ok(agent.get(&url).call()?)
+}
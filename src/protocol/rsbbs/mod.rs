@@ -20,6 +20,14 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+mod forum;
+mod pm;
+mod post;
+
+pub use forum::*;
+pub use pm::*;
+pub use post::*;
+
 use crate::{
     QuestionAnswerPair,
     error::{AgentError, LoginError},
@@ -0,0 +1,71 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{error::AgentError, protocol::rsbbs::RSBBSProtocolItem};
+use ureq::{Agent, Body, http::Response};
+
+/// 私人消息的会话列表。
+#[inline]
+pub fn pm_list(agent: &Agent, page: u32) -> Result<Response<Body>, AgentError> {
+    let url = format!(
+        "https://{}/home.php?mod=space&do=pm&filter=privatepm&page={page}",
+        RSBBSProtocolItem::Host
+    );
+    Ok(agent.get(&url).call()?)
+}
+/// 与某个用户的会话。
+#[inline]
+pub fn pm_view(agent: &Agent, touid: u64, page: u32) -> Result<Response<Body>, AgentError> {
+    let url = format!(
+        "https://{}/home.php?mod=space&do=pm&subop=view&touid={touid}&page={page}",
+        RSBBSProtocolItem::Host
+    );
+    Ok(agent.get(&url).call()?)
+}
+#[inline]
+pub fn pm_compose_url() -> String {
+    format!(
+        "https://{}/home.php?mod=spacecp&ac=pm",
+        RSBBSProtocolItem::Host
+    )
+}
+/// `touid` 为 `None` 时不带该参数，由表单中的 `username` 指定收件人。
+pub fn send_pm_url(touid: Option<u64>) -> String {
+    let touid = touid.map(|uid| format!("&touid={uid}")).unwrap_or_default();
+    format!(
+        "https://{}/home.php?mod=spacecp&ac=pm&op=send{touid}&pmsubmit=yes&inajax=1",
+        RSBBSProtocolItem::Host
+    )
+}
+/// 发送私人消息，`touid` 为 `None` 时按表单中的 `username` 发送。
+#[inline]
+pub fn send_pm(
+    agent: &Agent,
+    touid: Option<u64>,
+    referer: &str,
+    form: &[(&str, &str)],
+) -> Result<Response<Body>, AgentError> {
+    Ok(agent
+        .post(&send_pm_url(touid))
+        .header("Referer", referer)
+        .send_form(form.iter().copied())?)
+}
@@ -0,0 +1,80 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{error::AgentError, protocol::rsbbs::RSBBSProtocolItem};
+use ureq::{Agent, Body, http::Response};
+
+#[inline]
+pub fn reply_page_url(tid: u64, quote_pid: Option<u64>) -> String {
+    let mut url = format!(
+        "https://{}/forum.php?mod=post&action=reply&tid={tid}",
+        RSBBSProtocolItem::Host
+    );
+    if let Some(pid) = quote_pid {
+        url.push_str(&format!("&repquote={pid}"));
+    }
+    url
+}
+#[inline]
+pub fn new_thread_page_url(fid: u64) -> String {
+    format!(
+        "https://{}/forum.php?mod=post&action=newthread&fid={fid}",
+        RSBBSProtocolItem::Host
+    )
+}
+/// 回复或发帖的表单页面，`url` 见 [`reply_page_url`] 与 [`new_thread_page_url`].
+#[inline]
+pub fn post_page(agent: &Agent, url: &str) -> Result<Response<Body>, AgentError> {
+    Ok(agent.get(url).call()?)
+}
+#[inline]
+pub fn submit_reply(
+    agent: &Agent,
+    tid: u64,
+    referer: &str,
+    form: &[(&str, &str)],
+) -> Result<Response<Body>, AgentError> {
+    let url = format!(
+        "https://{}/forum.php?mod=post&action=reply&tid={tid}&extra=&replysubmit=yes&inajax=1",
+        RSBBSProtocolItem::Host
+    );
+    Ok(agent
+        .post(&url)
+        .header("Referer", referer)
+        .send_form(form.iter().copied())?)
+}
+#[inline]
+pub fn submit_new_thread(
+    agent: &Agent,
+    fid: u64,
+    referer: &str,
+    form: &[(&str, &str)],
+) -> Result<Response<Body>, AgentError> {
+    let url = format!(
+        "https://{}/forum.php?mod=post&action=newthread&fid={fid}&extra=&topicsubmit=yes&inajax=1",
+        RSBBSProtocolItem::Host
+    );
+    Ok(agent
+        .post(&url)
+        .header("Referer", referer)
+        .send_form(form.iter().copied())?)
+}
@@ -0,0 +1,314 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{EhallSession, error::EhallError, utils::de};
+use getset2::Getset2;
+use serde::{Deserialize, de::DeserializeOwned};
+use ureq::{Body, http::Response};
+
+// TODO: non_exhaustive fields.
+#[derive(Deserialize, Debug, Clone, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct App {
+    #[serde(rename = "appId")]
+    app_id: String,
+    #[serde(rename = "appName")]
+    app_name: String,
+    #[serde(rename = "middleIcon", default)]
+    middle_icon: String,
+    #[serde(rename = "type")]
+    app_type: i32,
+    description: Option<String>,
+    /// 应用版本，用作缓存的键，见 [`AppCache`](crate::AppCache).
+    #[serde(rename = "appVersion", deserialize_with = "de::opt_string", default)]
+    version: Option<String>,
+}
+
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum SortOrder {
+    Asc,
+    #[default]
+    Desc,
+}
+impl SortOrder {
+    #[inline]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortOrder::Asc => "asc",
+            SortOrder::Desc => "desc",
+        }
+    }
+}
+
+/// 应用目录的查询条件。
+#[derive(Debug, Copy, Clone)]
+pub struct AppListQuery<'a> {
+    pub search_key: &'a str,
+    pub page_size: u32,
+    /// 排序字段，见 `SORT_BY_*` 常量。
+    pub sort_key: &'a str,
+    pub order: SortOrder,
+}
+impl<'a> AppListQuery<'a> {
+    pub const SORT_BY_RECENT_USE_COUNT: &'static str = "recentUseCount";
+    pub const SORT_BY_USE_COUNT: &'static str = "useCount";
+    pub const SORT_BY_APP_NAME: &'static str = "appName";
+    pub const DEFAULT: Self = Self {
+        search_key: "",
+        page_size: 150,
+        sort_key: Self::SORT_BY_RECENT_USE_COUNT,
+        order: SortOrder::Desc,
+    };
+    #[inline]
+    pub fn new(search_key: &'a str) -> Self {
+        Self {
+            search_key,
+            ..Self::DEFAULT
+        }
+    }
+}
+impl Default for AppListQuery<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// 解析门户 `jsonp/*.json` 接口的 `{"hasLogin": .., "data": [..]}`.
+pub(super) fn parse_portal_data<T: DeserializeOwned>(text: &str) -> Result<Vec<T>, EhallError> {
+    #[derive(Deserialize)]
+    #[serde(bound = "T: DeserializeOwned")]
+    struct TmpData<T> {
+        #[serde(rename = "hasLogin")]
+        has_login: bool,
+        #[serde(default = "Vec::new")]
+        data: Vec<T>,
+    }
+    let TmpData { has_login, data } =
+        serde_json::from_str(text).map_err(|e| EhallError::ParseError(e.to_string()))?;
+    if has_login {
+        Ok(data)
+    } else {
+        Err(EhallError::NotLoggedIn)
+    }
+}
+
+pub(super) const PORTAL_PAGE_SIZE: u32 = 50;
+/// 逐页获取时的页数上限，防止服务器忽略分页参数时无限请求。
+const MAX_PORTAL_PAGES: u32 = 100;
+
+/// 逐页获取门户接口的数据，直到某一页不满 [`PORTAL_PAGE_SIZE`] 为止。
+pub(super) fn fetch_portal_pages<T: DeserializeOwned>(
+    fetch_page: impl Fn(u32) -> Result<Response<Body>, Box<ureq::Error>>,
+) -> Result<Vec<T>, EhallError> {
+    collect_portal_pages(|n| Ok(fetch_page(n)?.into_body().read_to_string()?))
+}
+
+/// 某一页与上一页内容相同时（服务器忽略了 `pageNumber`）视为没有更多数据；
+/// 超过 [`MAX_PORTAL_PAGES`] 页仍未结束时返回错误。
+fn collect_portal_pages<T: DeserializeOwned>(
+    fetch_text: impl Fn(u32) -> Result<String, EhallError>,
+) -> Result<Vec<T>, EhallError> {
+    let mut items = Vec::new();
+    let mut last_text = String::new();
+    for page_number in 1..=MAX_PORTAL_PAGES {
+        let text = fetch_text(page_number)?;
+        if text == last_text {
+            return Ok(items);
+        }
+        let mut page = parse_portal_data::<T>(&text)?;
+        let len = page.len();
+        items.append(&mut page);
+        if len < PORTAL_PAGE_SIZE as usize {
+            return Ok(items);
+        }
+        last_text = text;
+    }
+    Err(EhallError::ServerError(format!(
+        "超过 {MAX_PORTAL_PAGES} 页仍未获取完毕。"
+    )))
+}
+
+/// 逐页获取应用目录，直到某一页不满 `page_size` 为止。
+///
+/// 某一页的应用与上一页相同时（服务器忽略了 `pageNumber`）视为没有更多数据，
+/// 超过 100 页仍未结束时返回错误。
+pub struct AppPages<'a> {
+    session: &'a EhallSession,
+    query: AppListQuery<'a>,
+    page_number: u32,
+    last_ids: Vec<String>,
+    finished: bool,
+}
+impl AppPages<'_> {
+    fn accept(
+        &mut self,
+        page: Result<Vec<App>, EhallError>,
+    ) -> Option<Result<Vec<App>, EhallError>> {
+        let apps = match page {
+            Ok(apps) => apps,
+            Err(e) => {
+                self.finished = true;
+                return Some(Err(e));
+            }
+        };
+        let ids = apps.iter().map(|a| a.app_id.clone()).collect::<Vec<_>>();
+        if apps.is_empty() || ids == self.last_ids {
+            self.finished = true;
+            return None;
+        }
+        self.finished = apps.len() < self.query.page_size as usize;
+        self.page_number += 1;
+        self.last_ids = ids;
+        Some(Ok(apps))
+    }
+}
+impl Iterator for AppPages<'_> {
+    type Item = Result<Vec<App>, EhallError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        if self.page_number > MAX_PORTAL_PAGES {
+            self.finished = true;
+            return Some(Err(EhallError::ServerError(format!(
+                "超过 {MAX_PORTAL_PAGES} 页仍未获取完毕。"
+            ))));
+        }
+        let page = self.session.get_app_page(&self.query, self.page_number);
+        self.accept(page)
+    }
+}
+
+impl EhallSession {
+    /// 获取应用目录的某一页，`page_number` 从 1 开始。
+    pub fn get_app_page(
+        &self,
+        query: &AppListQuery,
+        page_number: u32,
+    ) -> Result<Vec<App>, EhallError> {
+        let text = crate::protocol::ehall::get_app_list(
+            self,
+            query.search_key,
+            page_number,
+            query.page_size,
+            query.sort_key,
+            query.order.as_str(),
+        )?
+        .into_body()
+        .read_to_string()?;
+        parse_portal_data(&text)
+    }
+    #[inline]
+    pub fn app_pages<'a>(&'a self, query: AppListQuery<'a>) -> AppPages<'a> {
+        AppPages {
+            session: self,
+            query,
+            page_number: 1,
+            last_ids: Vec::new(),
+            finished: false,
+        }
+    }
+    pub fn get_app_list(&self, search_key: &str) -> Result<Vec<App>, EhallError> {
+        let mut apps = Vec::new();
+        for page in self.app_pages(AppListQuery::new(search_key)) {
+            apps.append(&mut page?);
+        }
+        Ok(apps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_portal_data() {
+        let apps: Vec<App> = parse_portal_data(
+            r#"{"hasLogin":true,"data":[{"appId":"4770397878132218","appName":"我的课表","middleIcon":"/publicapp/icon.png","type":1,"description":null}]}"#,
+        )
+        .unwrap();
+        assert_eq!(apps.len(), 1);
+        assert_eq!(apps[0].app_name(), "我的课表");
+        assert!(matches!(
+            parse_portal_data::<App>(r#"{"hasLogin":false}"#),
+            Err(EhallError::NotLoggedIn)
+        ));
+    }
+
+    #[test]
+    fn test_app_pages() {
+        let session = EhallSession {
+            agent: crate::utils::build_agent(),
+        };
+        let apps = |ids: std::ops::Range<u32>| -> Vec<App> {
+            ids.map(|i| {
+                serde_json::from_value(serde_json::json!({
+                    "appId": i.to_string(), "appName": "", "type": 1
+                }))
+                .unwrap()
+            })
+            .collect()
+        };
+        let query = AppListQuery {
+            page_size: 2,
+            ..AppListQuery::DEFAULT
+        };
+        let mut pages = session.app_pages(query);
+        assert_eq!(pages.accept(Ok(apps(0..2))).unwrap().unwrap().len(), 2);
+        // 服务器忽略 `pageNumber`, 返回与上一页相同的内容。
+        assert!(pages.accept(Ok(apps(0..2))).is_none());
+        assert!(pages.next().is_none());
+        // 达到上限时不再请求。
+        let mut pages = session.app_pages(query);
+        pages.page_number = MAX_PORTAL_PAGES + 1;
+        assert!(matches!(
+            pages.next(),
+            Some(Err(EhallError::ServerError(_)))
+        ));
+        assert!(pages.next().is_none());
+    }
+
+    fn portal_page(range: std::ops::Range<u32>) -> String {
+        let data: Vec<String> = range.map(|i| i.to_string()).collect();
+        serde_json::json!({"hasLogin": true, "data": data}).to_string()
+    }
+
+    #[test]
+    fn test_collect_portal_pages() {
+        let size = PORTAL_PAGE_SIZE;
+        // 最后一页不满。
+        let items: Vec<String> =
+            collect_portal_pages(|n| Ok(portal_page((n - 1) * size..(n * size).min(size + 3))))
+                .unwrap();
+        assert_eq!(items.len(), size as usize + 3);
+        // 忽略 `pageNumber`, 每页都相同。
+        let items: Vec<String> = collect_portal_pages(|_| Ok(portal_page(0..size))).unwrap();
+        assert_eq!(items.len(), size as usize);
+        // 每页都是满的新数据，达到上限后报错。
+        assert!(matches!(
+            collect_portal_pages::<String>(|n| Ok(portal_page(n * size..(n + 1) * size))),
+            Err(EhallError::ServerError(_))
+        ));
+    }
+}
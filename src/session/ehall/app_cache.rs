@@ -0,0 +1,306 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{EhallSession, error::EhallError, session::ehall::App, utils::de};
+use getset2::Getset2;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// 应用的详细信息。
+#[derive(Serialize, Deserialize, Debug, Clone, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct AppDetail {
+    #[serde(rename = "appId", deserialize_with = "de::string_lenient")]
+    app_id: String,
+    #[serde(rename = "appName", deserialize_with = "de::string_lenient", default)]
+    app_name: String,
+    #[serde(
+        rename = "appVersion",
+        deserialize_with = "de::string_lenient",
+        default
+    )]
+    version: String,
+    #[serde(rename = "categoryName", deserialize_with = "de::opt_string", default)]
+    category: Option<String>,
+    #[serde(rename = "provider", deserialize_with = "de::opt_string", default)]
+    provider: Option<String>,
+    #[serde(rename = "description", deserialize_with = "de::opt_string", default)]
+    description: Option<String>,
+    #[serde(rename = "middleIcon", deserialize_with = "de::opt_string", default)]
+    middle_icon: Option<String>,
+    /// 是否需要额外授权才能使用。
+    #[serde(rename = "needAuth", deserialize_with = "de::bool_lenient", default)]
+    #[getset2(get_copy(pub), skip(get_ref))]
+    need_auth: bool,
+}
+
+/// 缓存中的应用，图标下载失败或应用没有图标时 `icon` 为 `None`.
+#[derive(Debug, Clone, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct CachedApp {
+    detail: AppDetail,
+    icon: Option<PathBuf>,
+}
+
+/// 应用信息与图标的磁盘缓存，以应用 id 和版本为键。
+///
+/// 没有版本号的应用无法判断缓存是否过期，每次都会重新获取。
+///
+/// 目录结构为 `<dir>/<app_id>/<version>/{detail.json,icon.<ext>}`.
+#[derive(Debug, Clone)]
+pub struct AppCache {
+    dir: PathBuf,
+}
+
+impl AppCache {
+    #[inline]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+    #[inline]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+    fn entry_dir(&self, app_id: &str, version: &str) -> PathBuf {
+        self.dir
+            .join(sanitize_key(app_id))
+            .join(sanitize_key(version))
+    }
+    /// 只读取缓存，不访问网络。`version` 为空时总是返回 `None`.
+    pub fn load(&self, app_id: &str, version: &str) -> Option<CachedApp> {
+        if version.trim().is_empty() {
+            return None;
+        }
+        let dir = self.entry_dir(app_id, version);
+        let detail = serde_json::from_slice(&fs::read(dir.join("detail.json")).ok()?).ok()?;
+        let icon = fs::read_dir(&dir).ok()?.find_map(|e| {
+            let path = e.ok()?.path();
+            path.file_stem()
+                .is_some_and(|s| s == "icon")
+                .then_some(path)
+        });
+        Some(CachedApp { detail, icon })
+    }
+    /// 获取应用信息与图标，`app` 带有版本且缓存命中时不访问网络。
+    pub fn get(&self, session: &EhallSession, app: &App) -> Result<CachedApp, EhallError> {
+        if let Some(cached) = app
+            .version()
+            .as_deref()
+            .and_then(|v| self.load(app.app_id(), v))
+        {
+            return Ok(cached);
+        }
+        self.refresh(session, app.app_id())
+    }
+    /// 重新获取应用信息，若该版本尚未缓存则写入缓存并下载图标。
+    pub fn refresh(&self, session: &EhallSession, app_id: &str) -> Result<CachedApp, EhallError> {
+        let detail = session.get_app_detail(app_id)?;
+        self.update(app_id, detail, |path| {
+            Ok(crate::protocol::ehall::get_app_icon(session, path)?
+                .into_body()
+                .read_to_vec()?)
+        })
+    }
+    /// 图标下载失败时仍写入应用信息，`icon` 为 `None`.
+    fn update(
+        &self,
+        app_id: &str,
+        detail: AppDetail,
+        get_icon: impl FnOnce(&str) -> Result<Vec<u8>, EhallError>,
+    ) -> Result<CachedApp, EhallError> {
+        if let Some(cached) = self.load(app_id, &detail.version) {
+            return Ok(cached);
+        }
+        let icon = detail
+            .middle_icon
+            .as_deref()
+            .and_then(|path| get_icon(path).ok());
+        self.store(app_id, detail, icon)
+    }
+    /// 写入缓存。没有版本号的应用会覆盖之前写入的内容。
+    fn store(
+        &self,
+        app_id: &str,
+        detail: AppDetail,
+        icon: Option<Vec<u8>>,
+    ) -> Result<CachedApp, EhallError> {
+        let dir = self.entry_dir(app_id, &detail.version);
+        if dir.exists() {
+            fs::remove_dir_all(&dir)?;
+        }
+        fs::create_dir_all(&dir)?;
+        let icon = match (icon, detail.middle_icon.as_deref()) {
+            (Some(bytes), Some(path)) => {
+                let icon = dir.join(format!("icon.{}", icon_extension(path)));
+                fs::write(&icon, bytes)?;
+                Some(icon)
+            }
+            _ => None,
+        };
+        // 最后写入 detail.json, 保证缓存目录中有它时图标也已就绪。
+        let json =
+            serde_json::to_vec(&detail).map_err(|e| EhallError::ParseError(e.to_string()))?;
+        fs::write(dir.join("detail.json"), json)?;
+        Ok(CachedApp { detail, icon })
+    }
+    /// 删除某个应用除 `keep_version` 以外的所有缓存版本。
+    pub fn prune(&self, app_id: &str, keep_version: &str) -> Result<(), EhallError> {
+        let keep = sanitize_key(keep_version);
+        let Ok(entries) = fs::read_dir(self.dir.join(sanitize_key(app_id))) else {
+            return Ok(());
+        };
+        for entry in entries {
+            let entry = entry?;
+            if entry.file_name() != keep.as_str() {
+                fs::remove_dir_all(entry.path())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl EhallSession {
+    /// 获取应用的详细信息。
+    pub fn get_app_detail(&self, app_id: &str) -> Result<AppDetail, EhallError> {
+        let text = crate::protocol::ehall::get_app_introduction(self, app_id)?
+            .into_body()
+            .read_to_string()?;
+        parse_app_detail(&text)
+    }
+}
+
+fn parse_app_detail(text: &str) -> Result<AppDetail, EhallError> {
+    #[derive(Deserialize)]
+    struct TmpData {
+        #[serde(rename = "hasLogin")]
+        has_login: bool,
+        data: Option<AppDetail>,
+    }
+    let TmpData { has_login, data } =
+        serde_json::from_str(text).map_err(|e| EhallError::ParseError(e.to_string()))?;
+    if !has_login {
+        return Err(EhallError::NotLoggedIn);
+    }
+    data.ok_or_else(|| EhallError::ServerError("应用不存在。".to_owned()))
+}
+
+/// 将 id 或版本号转为可用作目录名的形式。
+fn sanitize_key(key: &str) -> String {
+    let key: String = key
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    match key.as_str() {
+        "" | "." | ".." => format!("_{key}"),
+        _ => key,
+    }
+}
+
+fn icon_extension(path: &str) -> &str {
+    let name = path.split(['?', '#']).next().unwrap_or_default();
+    let name = name.rsplit('/').next().unwrap_or_default();
+    match name.rsplit_once('.') {
+        Some((_, ext))
+            if (1..=4).contains(&ext.len()) && ext.chars().all(|c| c.is_ascii_alphanumeric()) =>
+        {
+            ext
+        }
+        _ => "img",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_app_detail_round_trip() {
+        let detail = parse_app_detail(
+            r#"{"hasLogin":true,"data":{"appId":4770397878132218,"appName":"我的本研课表","appVersion":"1.0.3","categoryName":"教学","provider":"教务处","needAuth":"1","middleIcon":"/resources/icon.png?v=2"}}"#,
+        )
+        .unwrap();
+        assert_eq!(detail.app_id(), "4770397878132218");
+        assert!(detail.need_auth());
+        let json = serde_json::to_string(&detail).unwrap();
+        let detail: AppDetail = serde_json::from_str(&json).unwrap();
+        assert_eq!(detail.version(), "1.0.3");
+        assert_eq!(detail.provider().as_deref(), Some("教务处"));
+    }
+
+    #[test]
+    fn test_cache_keys() {
+        assert_eq!(sanitize_key("../a b"), ".._a_b");
+        assert_eq!(sanitize_key(".."), "_..");
+        assert_eq!(icon_extension("/resources/icon.png?v=2"), "png");
+        assert_eq!(icon_extension("/resources/icon"), "img");
+    }
+
+    #[test]
+    fn test_cache_store_and_prune() {
+        let dir = std::env::temp_dir().join(format!("x_l4rs-app-cache-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let cache = AppCache::new(&dir);
+        let detail = |version: &str| {
+            parse_app_detail(&format!(
+                r#"{{"hasLogin":true,"data":{{"appId":"1","appVersion":"{version}","middleIcon":"/icon.png"}}}}"#
+            ))
+            .unwrap()
+        };
+        assert!(cache.load("1", "1.0.3").is_none());
+        cache
+            .store("1", detail("1.0.3"), Some(vec![1, 2, 3]))
+            .unwrap();
+        let cached = cache.load("1", "1.0.3").unwrap();
+        assert_eq!(cached.detail().version(), "1.0.3");
+        let icon = cached.icon().as_ref().unwrap();
+        assert!(icon.ends_with("icon.png"));
+        assert_eq!(fs::read(icon).unwrap(), [1, 2, 3]);
+
+        cache.store("1", detail("1.0.4"), None).unwrap();
+        cache.prune("1", "1.0.4").unwrap();
+        assert!(cache.load("1", "1.0.3").is_none());
+        assert!(cache.load("1", "1.0.4").unwrap().icon().is_none());
+
+        // 图标下载失败。
+        let cached = cache
+            .update("1", detail("1.0.5"), |_| Err(EhallError::NotLoggedIn))
+            .unwrap();
+        assert!(cached.icon().is_none());
+        let cached = cache.load("1", "1.0.5").unwrap();
+        assert_eq!(cached.detail().version(), "1.0.5");
+        assert!(cached.icon().is_none());
+
+        // 没有版本号时写入的内容不会被当作缓存命中。
+        cache.store("1", detail(""), None).unwrap();
+        assert!(cache.load("1", "").is_none());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,240 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    EhallApp, EhallSession, Semester, Timetable,
+    error::EhallError,
+    utils::{IcsCalendar, de},
+};
+use chrono::{Days, FixedOffset, NaiveDate, Utc};
+use getset2::Getset2;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct TermRow {
+    /// 如 `2024-09-02 00:00:00`.
+    #[serde(rename = "XQKSRQ", deserialize_with = "de::string_lenient", default)]
+    start: String,
+    #[serde(rename = "XQJSRQ", deserialize_with = "de::string_lenient", default)]
+    end: String,
+    /// 总周数。
+    #[serde(rename = "ZZC", deserialize_with = "de::opt_u32_lenient", default)]
+    weeks: Option<u32>,
+}
+
+/// 学期及其起止日期。
+#[derive(Debug, Clone, Eq, PartialEq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct Term {
+    /// 如 `2024-2025-1`.
+    code: String,
+    name: String,
+    /// 第 1 周的周一。
+    #[getset2(get_copy(pub), skip(get_ref))]
+    start: NaiveDate,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    end: NaiveDate,
+}
+impl Term {
+    #[inline]
+    pub fn new(code: String, name: String, start: NaiveDate, end: NaiveDate) -> Self {
+        Self {
+            code,
+            name,
+            start,
+            end,
+        }
+    }
+    /// 教学周数。
+    #[inline]
+    pub fn weeks(&self) -> u32 {
+        ((self.end - self.start).num_days().max(0) as u32) / 7 + 1
+    }
+    #[inline]
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start <= date && date <= self.end
+    }
+    /// `date` 是第几周，不在学期内时为 `None`.
+    #[inline]
+    pub fn week_of(&self, date: NaiveDate) -> Option<u32> {
+        self.contains(date)
+            .then(|| (date - self.start).num_days() as u32 / 7 + 1)
+    }
+    fn from_row(semester: Semester, row: TermRow) -> Result<Self, EhallError> {
+        fn parse_date(s: &str) -> Option<NaiveDate> {
+            NaiveDate::parse_from_str(s.get(..10)?, "%Y-%m-%d").ok()
+        }
+        let start = parse_date(&row.start).ok_or_else(|| {
+            EhallError::ParseError(format!("学期开始日期无法解析：`{}`.", row.start))
+        })?;
+        let end = parse_date(&row.end)
+            .or_else(|| {
+                start.checked_add_days(Days::new(row.weeks.filter(|w| *w > 0)? as u64 * 7 - 1))
+            })
+            .ok_or_else(|| {
+                EhallError::ParseError(format!("学期结束日期无法解析：`{}`.", row.end))
+            })?;
+        let name = if semester.name().is_empty() {
+            semester.code().clone()
+        } else {
+            semester.name().clone()
+        };
+        Ok(Self::new(semester.code().clone(), name, start, end))
+    }
+}
+
+/// 学校所在时区（与 [`IcsCalendar::TZID`] 一致）的今天，不受本机时区影响。
+fn today() -> NaiveDate {
+    let offset = FixedOffset::east_opt(IcsCalendar::UTC_OFFSET_SECS).expect("时区偏移有误。");
+    Utc::now().with_timezone(&offset).date_naive()
+}
+
+/// 校历，新学期在前。
+#[derive(Debug, Clone, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct AcademicCalendar {
+    terms: Vec<Term>,
+    /// 教务系统认定的当前学期。
+    current: Option<String>,
+    /// 未找到起止日期或日期无法解析而被跳过的学期代码。
+    skipped: Vec<String>,
+}
+impl AcademicCalendar {
+    pub const TERM_PATH: &'static str = "modules/jshkcb/cxjcs.do";
+
+    pub fn term(app: &EhallApp, semester: Semester) -> Result<Term, EhallError> {
+        let (year, term) = semester.code().rsplit_once('-').ok_or_else(|| {
+            EhallError::ParseError(format!("学期代码有误：`{}`.", semester.code()))
+        })?;
+        let row = app
+            .emap(Self::TERM_PATH)
+            .param("XN", year)
+            .param("XQ", term)
+            .fetch_all::<TermRow>()?
+            .into_iter()
+            .next()
+            .ok_or_else(|| EhallError::ServerError(format!("未找到学期 `{}`.", semester.code())))?;
+        Term::from_row(semester, row)
+    }
+    /// 获取所有学期的起止日期。
+    ///
+    /// 每个学期需要单独请求一次。个别学期没有日期信息时跳过该学期并记录在 [`Self::skipped`] 中，
+    /// 网络错误与登录失效等仍会返回错误。
+    pub fn fetch(app: &EhallApp) -> Result<Self, EhallError> {
+        let current = match Timetable::current_semester(app) {
+            Ok(s) => Some(s.code().clone()),
+            Err(EhallError::ServerError(_)) => None,
+            Err(e) => return Err(e),
+        };
+        let mut terms = Vec::new();
+        let mut skipped = Vec::new();
+        for semester in Timetable::semesters(app)? {
+            let code = semester.code().clone();
+            match Self::term(app, semester) {
+                Ok(term) => terms.push(term),
+                Err(EhallError::ServerError(_) | EhallError::ParseError(_)) => skipped.push(code),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(Self {
+            terms,
+            current,
+            skipped,
+        })
+    }
+    #[inline]
+    pub fn find(&self, code: &str) -> Option<&Term> {
+        self.terms.iter().find(|t| t.code == code)
+    }
+    /// 当前学期。教务系统未给出时，取包含今天的学期。
+    pub fn current_term(&self) -> Option<&Term> {
+        self.current
+            .as_deref()
+            .and_then(|c| self.find(c))
+            .or_else(|| self.term_on(today()))
+    }
+    #[inline]
+    pub fn term_on(&self, date: NaiveDate) -> Option<&Term> {
+        self.terms.iter().find(|t| t.contains(date))
+    }
+    /// 当前是第几周，不在学期内时为 `None`.
+    #[inline]
+    pub fn current_week(&self) -> Option<u32> {
+        self.current_term()?.week_of(today())
+    }
+}
+
+impl EhallSession {
+    #[inline]
+    pub fn get_academic_calendar(&self) -> Result<AcademicCalendar, EhallError> {
+        AcademicCalendar::fetch(&self.open_timetable_app()?)
+    }
+    pub fn get_current_term(&self) -> Result<Term, EhallError> {
+        let app = self.open_timetable_app()?;
+        AcademicCalendar::term(&app, Timetable::current_semester(&app)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_term() {
+        let semester: Semester =
+            serde_json::from_str(r#"{"DM":"2024-2025-1","MC":"2024-2025学年第一学期"}"#).unwrap();
+        let row: TermRow =
+            serde_json::from_str(r#"{"XQKSRQ":"2024-09-02 00:00:00","ZZC":"20"}"#).unwrap();
+        let term = Term::from_row(semester, row).unwrap();
+        assert_eq!(term.end(), NaiveDate::from_ymd_opt(2025, 1, 19).unwrap());
+        assert_eq!(term.weeks(), 20);
+        assert_eq!(
+            term.week_of(NaiveDate::from_ymd_opt(2024, 9, 8).unwrap()),
+            Some(1)
+        );
+        assert_eq!(
+            term.week_of(NaiveDate::from_ymd_opt(2024, 9, 9).unwrap()),
+            Some(2)
+        );
+        assert_eq!(
+            term.week_of(NaiveDate::from_ymd_opt(2025, 2, 1).unwrap()),
+            None
+        );
+        // 学期边界。
+        let date = |m, d| NaiveDate::from_ymd_opt(if m > 6 { 2024 } else { 2025 }, m, d).unwrap();
+        assert_eq!(term.week_of(date(9, 1)), None);
+        assert_eq!(term.week_of(date(9, 2)), Some(1));
+        assert_eq!(term.week_of(date(1, 13)), Some(20));
+        assert_eq!(term.week_of(date(1, 19)), Some(20));
+        assert_eq!(term.week_of(date(1, 20)), None);
+        let calendar = AcademicCalendar {
+            terms: vec![term],
+            current: None,
+            skipped: Vec::new(),
+        };
+        assert_eq!(
+            calendar.term_on(date(9, 2)).map(Term::code).unwrap(),
+            "2024-2025-1"
+        );
+        assert!(calendar.term_on(date(1, 20)).is_none());
+    }
+}
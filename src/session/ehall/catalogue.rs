@@ -0,0 +1,308 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    Course, EhallApp, EhallSession, EmapBuilder, EmapCondition, EmapPage, EmapPages, EmapQuery,
+    SortOrder, error::EhallError, utils::de,
+};
+use getset2::Getset2;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct CatalogueRow {
+    #[serde(flatten)]
+    meeting: Course,
+    #[serde(
+        rename = "KKDWDM_DISPLAY",
+        deserialize_with = "de::opt_string",
+        default
+    )]
+    college: Option<String>,
+    #[serde(rename = "XF", deserialize_with = "de::f64_lenient", default)]
+    credit: f64,
+    #[serde(rename = "KRL", deserialize_with = "de::opt_u32_lenient", default)]
+    capacity: Option<u32>,
+    #[serde(rename = "XKRS", deserialize_with = "de::opt_u32_lenient", default)]
+    enrolled: Option<u32>,
+}
+
+/// 开课的一个教学班。
+#[derive(Debug, Clone, PartialEq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct OfferedSection {
+    course_code: String,
+    course_name: String,
+    /// 课序号。
+    class_code: String,
+    teacher: Option<String>,
+    /// 开课单位。
+    college: Option<String>,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    credit: f64,
+    /// 课容量。
+    #[getset2(get_copy(pub), skip(get_ref))]
+    capacity: Option<u32>,
+    /// 已选人数。
+    #[getset2(get_copy(pub), skip(get_ref))]
+    enrolled: Option<u32>,
+    /// 上课安排，尚未排课时为空。
+    meetings: Vec<Course>,
+}
+impl OfferedSection {
+    /// 剩余名额。
+    #[inline]
+    pub fn remaining(&self) -> Option<u32> {
+        Some(self.capacity?.saturating_sub(self.enrolled?))
+    }
+}
+
+/// 同一教学班的多次安排分布在多行中，按课程号与课序号合并，保持原有顺序。
+fn group_sections(rows: Vec<CatalogueRow>) -> Vec<OfferedSection> {
+    let mut sections: Vec<OfferedSection> = Vec::new();
+    for row in rows {
+        let m = row.meeting;
+        let section = match sections
+            .iter_mut()
+            .find(|s| &s.course_code == m.course_code() && &s.class_code == m.class_code())
+        {
+            Some(s) => s,
+            None => {
+                sections.push(OfferedSection {
+                    course_code: m.course_code().clone(),
+                    course_name: m.name().clone(),
+                    class_code: m.class_code().clone(),
+                    teacher: m.teacher().clone(),
+                    college: row.college,
+                    credit: row.credit,
+                    capacity: row.capacity,
+                    enrolled: row.enrolled,
+                    meetings: Vec::new(),
+                });
+                sections.last_mut().expect("just pushed")
+            }
+        };
+        if m.weekday() != 0 {
+            section.meetings.push(m);
+        }
+    }
+    sections
+}
+/// 取出末尾属于同一教学班的行，它们可能在下一页继续。
+fn split_last_section(rows: &mut Vec<CatalogueRow>) -> Vec<CatalogueRow> {
+    let Some(last) = rows.last() else {
+        return Vec::new();
+    };
+    let key = |r: &CatalogueRow| {
+        (
+            r.meeting.course_code().clone(),
+            r.meeting.class_code().clone(),
+        )
+    };
+    let last = key(last);
+    let start = rows
+        .iter()
+        .rposition(|r| key(r) != last)
+        .map_or(0, |i| i + 1);
+    rows.split_off(start)
+}
+
+/// 逐页获取的开课信息，见 [`CourseSearch::pages`].
+///
+/// 跨页的教学班会被合并后在后一页中给出，因此每项的教学班数量不一定等于页大小。
+pub struct OfferedSectionPages<'a> {
+    pages: EmapPages<'a, CatalogueRow>,
+    pending: Vec<CatalogueRow>,
+}
+impl Iterator for OfferedSectionPages<'_> {
+    type Item = Result<Vec<OfferedSection>, EhallError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.pages.next() {
+                Some(Ok(page)) => {
+                    let mut rows = std::mem::take(&mut self.pending);
+                    rows.extend(page.rows);
+                    self.pending = split_last_section(&mut rows);
+                    if !rows.is_empty() {
+                        return Some(Ok(group_sections(rows)));
+                    }
+                }
+                Some(Err(e)) => return Some(Err(e)),
+                None if self.pending.is_empty() => return None,
+                None => return Some(Ok(group_sections(std::mem::take(&mut self.pending)))),
+            }
+        }
+    }
+}
+
+/// 全校开课查询的条件，未给出的条件不限制。
+///
+/// 开课单位按代码精确匹配，其余条件均为模糊匹配。
+#[derive(Debug, Clone, Default)]
+pub struct CourseSearch<'a> {
+    /// 学年学期，如 `2024-2025-1`.
+    pub semester: &'a str,
+    pub course_name: Option<&'a str>,
+    pub teacher: Option<&'a str>,
+    /// 开课单位代码，如 `0001`.
+    pub college_code: Option<&'a str>,
+    pub course_code: Option<&'a str>,
+}
+impl CourseSearch<'_> {
+    /// 全校课表查询。
+    pub const APP_ID: &'static str = "4770379436498946";
+    pub const SEARCH_PATH: &'static str = "modules/qxkcb/qxfbkccx.do";
+
+    fn conditions(&self) -> Vec<EmapCondition> {
+        let mut conditions = vec![EmapCondition::new(
+            "XNXQDM",
+            EmapBuilder::Equal,
+            self.semester,
+        )];
+        if let Some(college_code) = self.college_code {
+            conditions.push(EmapCondition::new(
+                "KKDWDM",
+                EmapBuilder::Equal,
+                college_code,
+            ));
+        }
+        for (name, value) in [
+            ("KCM", self.course_name),
+            ("SKJS", self.teacher),
+            ("KCH", self.course_code),
+        ] {
+            if let Some(value) = value {
+                conditions.push(EmapCondition::new(name, EmapBuilder::Include, value));
+            }
+        }
+        conditions
+    }
+    fn query<'a>(&self, app: &'a EhallApp<'a>) -> EmapQuery<'a> {
+        self.conditions().into_iter().fold(
+            app.emap(Self::SEARCH_PATH)
+                .order_by("KCH", SortOrder::Asc)
+                .order_by("KXH", SortOrder::Asc),
+            EmapQuery::condition,
+        )
+    }
+    /// 获取一页结果。同一教学班可能跨页，此时会在相邻两页中各出现一次。
+    pub fn fetch_page(
+        &self,
+        app: &EhallApp,
+        page_number: u32,
+        page_size: u32,
+    ) -> Result<EmapPage<OfferedSection>, EhallError> {
+        let EmapPage {
+            page_number,
+            total_size,
+            rows,
+        } = self
+            .query(app)
+            .page_size(page_size)
+            .fetch_page::<CatalogueRow>(page_number)?;
+        Ok(EmapPage {
+            page_number,
+            total_size,
+            rows: group_sections(rows),
+        })
+    }
+    /// 按需逐页获取，跨页的教学班会被合并。
+    pub fn pages<'a>(&self, app: &'a EhallApp<'a>, page_size: u32) -> OfferedSectionPages<'a> {
+        OfferedSectionPages {
+            pages: self.query(app).page_size(page_size).pages(),
+            pending: Vec::new(),
+        }
+    }
+    /// 获取全部结果。结果可能很多，必要时请使用 [`CourseSearch::pages`].
+    pub fn fetch_all(&self, app: &EhallApp) -> Result<Vec<OfferedSection>, EhallError> {
+        Ok(group_sections(self.query(app).fetch_all()?))
+    }
+}
+
+impl EhallSession {
+    /// 仅获取第一页。需要更多结果时，请通过 [`EhallSession::open_app`] 打开
+    /// [`CourseSearch::APP_ID`] 后使用 [`CourseSearch::pages`].
+    #[inline]
+    pub fn search_courses(
+        &self,
+        search: &CourseSearch,
+    ) -> Result<EmapPage<OfferedSection>, EhallError> {
+        search.fetch_page(
+            &self.open_app(CourseSearch::APP_ID)?,
+            1,
+            EmapQuery::DEFAULT_PAGE_SIZE,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_sections() {
+        let rows: Vec<CatalogueRow> = serde_json::from_str(
+            r#"[{"KCH":"MA101","KCM":"高等数学","KXH":"01","SKJS":"张三","SKXQ":1,"KSJC":1,"JSJC":2,"SKZC":"11","JASMC":"B-101","KKDWDM_DISPLAY":"数学与统计学院","XF":"5","KRL":"120","XKRS":100},
+                {"KCH":"MA101","KCM":"高等数学","KXH":"01","SKJS":"张三","SKXQ":3,"KSJC":3,"JSJC":4,"SKZC":"11","JASMC":"B-102","XF":"5","KRL":"120","XKRS":100},
+                {"KCH":"MA101","KCM":"高等数学","KXH":"02","SKJS":"李四","SKXQ":"","XF":"5"}]"#,
+        )
+        .unwrap();
+        let sections = group_sections(rows);
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].meetings().len(), 2);
+        assert_eq!(sections[0].remaining(), Some(20));
+        assert!(sections[1].meetings().is_empty());
+    }
+    #[test]
+    fn test_split_last_section() {
+        let mut rows: Vec<CatalogueRow> = serde_json::from_str(
+            r#"[{"KCH":"MA101","KCM":"高等数学","KXH":"01","SKXQ":1,"KSJC":1,"JSJC":2,"SKZC":"11"},
+                {"KCH":"MA101","KCM":"高等数学","KXH":"02","SKXQ":1,"KSJC":1,"JSJC":2,"SKZC":"11"},
+                {"KCH":"MA101","KCM":"高等数学","KXH":"02","SKXQ":3,"KSJC":3,"JSJC":4,"SKZC":"11"}]"#,
+        )
+        .unwrap();
+        let pending = split_last_section(&mut rows);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(pending.len(), 2);
+        assert!(pending.iter().all(|r| r.meeting.class_code() == "02"));
+        let mut rest = pending;
+        assert_eq!(split_last_section(&mut rest).len(), 2);
+        assert!(rest.is_empty());
+    }
+    #[test]
+    fn test_course_search_query() {
+        let search = CourseSearch {
+            semester: "2024-2025-1",
+            college_code: Some("0001"),
+            course_name: Some("数学"),
+            ..Default::default()
+        };
+        assert_eq!(
+            search.conditions(),
+            vec![
+                EmapCondition::new("XNXQDM", EmapBuilder::Equal, "2024-2025-1"),
+                EmapCondition::new("KKDWDM", EmapBuilder::Equal, "0001"),
+                EmapCondition::new("KCM", EmapBuilder::Include, "数学"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,210 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{EhallApp, EhallSession, SortOrder, error::EhallError, utils::de};
+use chrono::NaiveDate;
+use getset2::Getset2;
+use serde::Deserialize;
+use std::ops::RangeInclusive;
+
+/// 校区或教学楼等代码项。
+#[derive(Deserialize, Debug, Clone, Eq, PartialEq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct CodeItem {
+    #[serde(rename = "DM", deserialize_with = "de::string_lenient", default)]
+    code: String,
+    #[serde(rename = "MC", deserialize_with = "de::string_lenient", default)]
+    name: String,
+}
+
+/// 空闲教室。
+#[derive(Deserialize, Debug, Clone, Eq, PartialEq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct Classroom {
+    #[serde(rename = "JASDM", deserialize_with = "de::string_lenient", default)]
+    code: String,
+    #[serde(rename = "JASMC", deserialize_with = "de::string_lenient", default)]
+    name: String,
+    #[serde(
+        rename = "XXXQDM_DISPLAY",
+        deserialize_with = "de::opt_string",
+        default
+    )]
+    campus: Option<String>,
+    #[serde(rename = "JXLDM_DISPLAY", deserialize_with = "de::opt_string", default)]
+    building: Option<String>,
+    /// 座位数，后端未给出时为 `None`.
+    #[serde(rename = "SKZWS", deserialize_with = "de::opt_u32_lenient", default)]
+    #[getset2(get_copy(pub), skip(get_ref))]
+    capacity: Option<u32>,
+    /// 教室类型，如“多媒体教室”。
+    #[serde(
+        rename = "JASLXDM_DISPLAY",
+        deserialize_with = "de::opt_string",
+        default
+    )]
+    room_type: Option<String>,
+}
+
+/// 查询哪一天。
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ClassroomDay<'a> {
+    Date(NaiveDate),
+    Week {
+        semester: &'a str,
+        week: u32,
+        /// 1 为周一，7 为周日。
+        weekday: u32,
+    },
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ClassroomQuery<'a> {
+    /// 校区代码，见 [`EhallSession::get_campuses`].
+    pub campus: &'a str,
+    /// 教学楼代码，为 `None` 时查询整个校区。
+    pub building: Option<&'a str>,
+    pub day: ClassroomDay<'a>,
+    /// 节次范围，这些节次均空闲的教室才会被返回。
+    pub sections: RangeInclusive<u32>,
+}
+impl ClassroomQuery<'_> {
+    /// 空闲教室查询。
+    pub const APP_ID: &'static str = "4768402106681759";
+    pub const CAMPUSES_PATH: &'static str = "modules/kxjas/xxxqcx.do";
+    pub const BUILDINGS_PATH: &'static str = "modules/kxjas/jxlcx.do";
+    pub const CLASSROOMS_PATH: &'static str = "modules/kxjas/cxkxjas.do";
+
+    /// 查询条件对应的 EMAP 参数。
+    fn params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![
+            ("XXXQDM", self.campus.to_owned()),
+            ("KSJC", self.sections.start().to_string()),
+            ("JSJC", self.sections.end().to_string()),
+        ];
+        if let Some(building) = self.building {
+            params.push(("JXLDM", building.to_owned()));
+        }
+        match self.day {
+            ClassroomDay::Date(date) => params.push(("RQ", date.format("%Y-%m-%d").to_string())),
+            ClassroomDay::Week {
+                semester,
+                week,
+                weekday,
+            } => params.extend([
+                ("XNXQDM", semester.to_owned()),
+                ("ZC", week.to_string()),
+                ("XQ", weekday.to_string()),
+            ]),
+        }
+        params
+    }
+    pub fn fetch(&self, app: &EhallApp) -> Result<Vec<Classroom>, EhallError> {
+        let params = self.params();
+        let mut query = app
+            .emap(Self::CLASSROOMS_PATH)
+            .order_by("JASMC", SortOrder::Asc);
+        for (name, value) in &params {
+            query = query.param(name, value);
+        }
+        query.fetch_all()
+    }
+}
+impl EhallSession {
+    #[inline]
+    pub fn get_campuses(&self) -> Result<Vec<CodeItem>, EhallError> {
+        self.open_app(ClassroomQuery::APP_ID)?
+            .emap(ClassroomQuery::CAMPUSES_PATH)
+            .fetch_all()
+    }
+    #[inline]
+    pub fn get_buildings(&self, campus: &str) -> Result<Vec<CodeItem>, EhallError> {
+        self.open_app(ClassroomQuery::APP_ID)?
+            .emap(ClassroomQuery::BUILDINGS_PATH)
+            .param("XXXQDM", campus)
+            .fetch_all()
+    }
+    #[inline]
+    pub fn find_empty_classrooms(
+        &self,
+        query: &ClassroomQuery,
+    ) -> Result<Vec<Classroom>, EhallError> {
+        query.fetch(&self.open_app(ClassroomQuery::APP_ID)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classroom_query_params() {
+        let query = ClassroomQuery {
+            campus: "2",
+            building: Some("B"),
+            day: ClassroomDay::Date(NaiveDate::from_ymd_opt(2024, 10, 8).unwrap()),
+            sections: 3..=4,
+        };
+        assert_eq!(
+            query.params(),
+            [
+                ("XXXQDM", "2".to_owned()),
+                ("KSJC", "3".to_owned()),
+                ("JSJC", "4".to_owned()),
+                ("JXLDM", "B".to_owned()),
+                ("RQ", "2024-10-08".to_owned()),
+            ]
+        );
+        let query = ClassroomQuery {
+            building: None,
+            day: ClassroomDay::Week {
+                semester: "2024-2025-1",
+                week: 6,
+                weekday: 2,
+            },
+            ..query
+        };
+        assert_eq!(
+            &query.params()[3..],
+            [
+                ("XNXQDM", "2024-2025-1".to_owned()),
+                ("ZC", "6".to_owned()),
+                ("XQ", "2".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_classroom() {
+        let buildings: Vec<CodeItem> =
+            serde_json::from_str(r#"[{"DM":"B","MC":"B楼"},{"DM":1,"MC":"信远楼"}]"#).unwrap();
+        assert_eq!(buildings[0].name(), "B楼");
+        assert_eq!(buildings[1].code(), "1");
+        let room: Classroom = serde_json::from_str(
+            r#"{"JASDM":"B-101","JASMC":"B-101","XXXQDM_DISPLAY":"南校区","JXLDM_DISPLAY":"B楼","SKZWS":"120","JASLXDM_DISPLAY":""}"#,
+        )
+        .unwrap();
+        assert_eq!(room.campus().as_deref(), Some("南校区"));
+        assert_eq!(room.capacity(), Some(120));
+        assert_eq!(room.room_type(), &None);
+    }
+}
@@ -0,0 +1,374 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{EhallApp, SortOrder, error::EhallError};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::Value;
+
+/// `querySetting` 中条件的比较方式。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize)]
+pub enum EmapBuilder {
+    #[serde(rename = "equal")]
+    Equal,
+    #[serde(rename = "notEqual")]
+    NotEqual,
+    /// 模糊匹配。
+    #[serde(rename = "include")]
+    Include,
+    #[serde(rename = "notInclude")]
+    NotInclude,
+    #[serde(rename = "more")]
+    More,
+    #[serde(rename = "moreEqual")]
+    MoreEqual,
+    #[serde(rename = "less")]
+    Less,
+    #[serde(rename = "lessEqual")]
+    LessEqual,
+    /// 多值匹配，`value` 以 `,` 分隔。
+    #[serde(rename = "m_value_equal")]
+    MValueEqual,
+    #[serde(rename = "empty")]
+    Empty,
+    #[serde(rename = "notEmpty")]
+    NotEmpty,
+}
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Serialize)]
+pub enum LinkOpt {
+    #[default]
+    #[serde(rename = "and")]
+    And,
+    #[serde(rename = "or")]
+    Or,
+}
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct EmapCondition {
+    pub name: String,
+    pub value: String,
+    #[serde(rename = "linkOpt")]
+    pub link_opt: LinkOpt,
+    pub builder: EmapBuilder,
+}
+impl EmapCondition {
+    #[inline]
+    pub fn new(name: impl Into<String>, builder: EmapBuilder, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+            link_opt: LinkOpt::And,
+            builder,
+        }
+    }
+}
+
+/// 一页查询结果。
+#[derive(Debug, Clone)]
+pub struct EmapPage<T> {
+    pub page_number: u32,
+    pub total_size: u32,
+    pub rows: Vec<T>,
+}
+
+/// ehall 中 EMAP 后端 `*.do` 接口的通用查询。
+///
+/// ``` no_run
+/// # use x_l4rs::{EhallApp, EmapBuilder, SortOrder, error::EhallError};
+/// # fn f(app: &EhallApp) -> Result<(), EhallError> {
+/// let rows: Vec<serde_json::Value> = app
+///     .emap("modules/xskcb/xskcb.do")
+///     .filter("XNXQDM", EmapBuilder::Equal, "2024-2025-1")
+///     .order_by("KSJC", SortOrder::Asc)
+///     .fetch_all()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct EmapQuery<'a> {
+    app: &'a EhallApp<'a>,
+    path: String,
+    conditions: Vec<EmapCondition>,
+    order: Vec<(String, SortOrder)>,
+    params: Vec<(String, String)>,
+    page_size: u32,
+}
+impl<'a> EmapQuery<'a> {
+    pub const DEFAULT_PAGE_SIZE: u32 = 100;
+    #[inline]
+    pub fn new(app: &'a EhallApp<'a>, path: &str) -> Self {
+        Self {
+            app,
+            path: path.to_owned(),
+            conditions: Vec::new(),
+            order: Vec::new(),
+            params: Vec::new(),
+            page_size: Self::DEFAULT_PAGE_SIZE,
+        }
+    }
+    /// 接口名，即路径最后一段去掉 `.do`, 也是响应中 `datas` 下的键。
+    #[inline]
+    pub fn action(&self) -> &str {
+        let action = self.path.rsplit('/').next().unwrap_or_default();
+        action.strip_suffix(".do").unwrap_or(action)
+    }
+    #[inline]
+    pub fn condition(mut self, condition: EmapCondition) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+    #[inline]
+    pub fn filter(self, name: &str, builder: EmapBuilder, value: &str) -> Self {
+        self.condition(EmapCondition::new(name, builder, value))
+    }
+    #[inline]
+    pub fn or_filter(self, name: &str, builder: EmapBuilder, value: &str) -> Self {
+        self.condition(EmapCondition {
+            link_opt: LinkOpt::Or,
+            ..EmapCondition::new(name, builder, value)
+        })
+    }
+    #[inline]
+    pub fn order_by(mut self, name: &str, order: SortOrder) -> Self {
+        self.order.push((name.to_owned(), order));
+        self
+    }
+    /// 附加的表单参数，部分接口直接以字段名作为参数而不使用 `querySetting`.
+    #[inline]
+    pub fn param(mut self, name: &str, value: &str) -> Self {
+        self.params.push((name.to_owned(), value.to_owned()));
+        self
+    }
+    #[inline]
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+    fn query_setting(&self) -> String {
+        serde_json::to_string(&self.conditions).expect("failed to serialize querySetting.")
+    }
+    fn order_setting(&self) -> String {
+        self.order
+            .iter()
+            .map(|(name, order)| match order {
+                SortOrder::Asc => format!("+{name}"),
+                SortOrder::Desc => format!("-{name}"),
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+    /// 获取原始的 `datas.<action>` 对象。
+    pub fn fetch_raw(&self, page_number: u32) -> Result<Value, EhallError> {
+        let query_setting = self.query_setting();
+        let order = self.order_setting();
+        let page_size = self.page_size.to_string();
+        let page_number = page_number.to_string();
+        let mut form = vec![
+            ("querySetting", query_setting.as_str()),
+            ("pageSize", page_size.as_str()),
+            ("pageNumber", page_number.as_str()),
+        ];
+        if !order.is_empty() {
+            form.push(("*order", order.as_str()));
+        }
+        form.extend(self.params.iter().map(|(k, v)| (k.as_str(), v.as_str())));
+        let text = self
+            .app
+            .post_form(&self.path, &form)?
+            .into_body()
+            .read_to_string()?;
+        parse_emap_datas(&text, self.action())
+    }
+    pub fn fetch_page<T: DeserializeOwned>(
+        &self,
+        page_number: u32,
+    ) -> Result<EmapPage<T>, EhallError> {
+        let datas = self.fetch_raw(page_number)?;
+        parse_emap_page(datas, page_number)
+    }
+    #[inline]
+    pub fn pages<T: DeserializeOwned>(self) -> EmapPages<'a, T> {
+        EmapPages {
+            query: self,
+            page_number: 1,
+            fetched: 0,
+            finished: false,
+            _marker: std::marker::PhantomData,
+        }
+    }
+    pub fn fetch_all<T: DeserializeOwned>(self) -> Result<Vec<T>, EhallError> {
+        let mut rows = Vec::new();
+        for page in self.pages() {
+            rows.append(&mut page?.rows);
+        }
+        Ok(rows)
+    }
+}
+
+/// 逐页获取，直到取满 `totalSize` 条或某页为空。
+pub struct EmapPages<'a, T> {
+    query: EmapQuery<'a>,
+    page_number: u32,
+    fetched: u32,
+    finished: bool,
+    _marker: std::marker::PhantomData<T>,
+}
+impl<T: DeserializeOwned> Iterator for EmapPages<'_, T> {
+    type Item = Result<EmapPage<T>, EhallError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        match self.query.fetch_page::<T>(self.page_number) {
+            Ok(page) => {
+                self.fetched += page.rows.len() as u32;
+                self.page_number += 1;
+                self.finished = page.rows.is_empty() || self.fetched >= page.total_size;
+                if page.rows.is_empty() {
+                    None
+                } else {
+                    Some(Ok(page))
+                }
+            }
+            Err(e) => {
+                self.finished = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+fn parse_emap_datas(text: &str, action: &str) -> Result<Value, EhallError> {
+    #[derive(Deserialize)]
+    struct TmpData {
+        #[serde(default)]
+        code: Value,
+        #[serde(default)]
+        msg: Option<String>,
+        #[serde(default)]
+        datas: Value,
+    }
+    let TmpData { code, msg, datas } = serde_json::from_str(text).map_err(|e| {
+        if text.trim_start().starts_with('<') {
+            EhallError::NotLoggedIn
+        } else {
+            EhallError::ParseError(e.to_string())
+        }
+    })?;
+    let ok = match &code {
+        Value::Null => true,
+        Value::String(c) => c == "0",
+        Value::Number(c) => c.as_i64() == Some(0),
+        _ => false,
+    };
+    if !ok {
+        return Err(EhallError::ServerError(
+            msg.unwrap_or_else(|| code.to_string()),
+        ));
+    }
+    match datas {
+        Value::Object(mut datas) => match datas.remove(action) {
+            Some(d) => Ok(d),
+            None if datas.len() == 1 => Ok(datas.into_iter().next().expect("checked").1),
+            None => Ok(Value::Object(datas)),
+        },
+        datas => Ok(datas),
+    }
+}
+
+fn parse_emap_page<T: DeserializeOwned>(
+    datas: Value,
+    page_number: u32,
+) -> Result<EmapPage<T>, EhallError> {
+    #[derive(Deserialize)]
+    struct TmpData {
+        #[serde(rename = "totalSize", default)]
+        total_size: u32,
+        #[serde(default)]
+        rows: Vec<Value>,
+    }
+    let TmpData { total_size, rows } =
+        serde_json::from_value(datas).map_err(|e| EhallError::ParseError(e.to_string()))?;
+    let rows = rows
+        .into_iter()
+        .map(serde_json::from_value)
+        .collect::<Result<Vec<T>, _>>()
+        .map_err(|e| EhallError::ParseError(e.to_string()))?;
+    Ok(EmapPage {
+        page_number,
+        total_size,
+        rows,
+    })
+}
+
+impl<'a> EhallApp<'a> {
+    /// 在应用内构造 EMAP 查询，`path` 相对于应用的根地址，如 `modules/xskcb/xskcb.do`.
+    #[inline]
+    pub fn emap(&'a self, path: &str) -> EmapQuery<'a> {
+        EmapQuery::new(self, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_emap_rows<T: DeserializeOwned>(
+        text: &str,
+        action: &str,
+    ) -> Result<Vec<T>, EhallError> {
+        parse_emap_page(parse_emap_datas(text, action)?, 1).map(|p| p.rows)
+    }
+    #[test]
+    fn test_parse_emap() {
+        #[derive(Deserialize)]
+        struct Row {
+            #[serde(rename = "KCM")]
+            kcm: String,
+        }
+        let text = r#"{"code":"0","datas":{"xskcb":{"totalSize":2,"pageSize":10,"rows":[{"KCM":"高等数学"},{"KCM":"大学物理"}]}}}"#;
+        let rows: Vec<Row> = parse_emap_rows(text, "xskcb").unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1].kcm, "大学物理");
+        assert!(matches!(
+            parse_emap_rows::<Row>(r#"{"code":"1","msg":"参数错误"}"#, "xskcb"),
+            Err(EhallError::ServerError(_))
+        ));
+        assert!(matches!(
+            parse_emap_rows::<Row>("<html></html>", "xskcb"),
+            Err(EhallError::NotLoggedIn)
+        ));
+    }
+    #[test]
+    fn test_query_setting() {
+        let conditions = vec![
+            EmapCondition::new("XNXQDM", EmapBuilder::Equal, "2024-2025-1"),
+            EmapCondition {
+                link_opt: LinkOpt::Or,
+                ..EmapCondition::new("KCM", EmapBuilder::Include, "数学")
+            },
+        ];
+        assert_eq!(
+            serde_json::to_string(&conditions).unwrap(),
+            r#"[{"name":"XNXQDM","value":"2024-2025-1","linkOpt":"and","builder":"equal"},{"name":"KCM","value":"数学","linkOpt":"or","builder":"include"}]"#
+        );
+    }
+}
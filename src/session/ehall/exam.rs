@@ -0,0 +1,243 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    EhallApp, EhallSession, EmapBuilder, SortOrder,
+    error::EhallError,
+    utils::{IcsCalendar, IcsEvent, de},
+};
+use chrono::{NaiveDate, NaiveTime};
+use getset2::Getset2;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct ExamRow {
+    #[serde(rename = "XNXQDM", deserialize_with = "de::string_lenient", default)]
+    semester: String,
+    #[serde(rename = "KCM", deserialize_with = "de::string_lenient", default)]
+    course_name: String,
+    #[serde(rename = "KCH", deserialize_with = "de::string_lenient", default)]
+    course_code: String,
+    #[serde(rename = "WID", deserialize_with = "de::opt_string", default)]
+    arrangement_id: Option<String>,
+    #[serde(rename = "KSMC", deserialize_with = "de::opt_string", default)]
+    exam_name: Option<String>,
+    /// 如 `2024-01-08 14:00-16:00(星期一)`.
+    #[serde(rename = "KSSJMS", deserialize_with = "de::string_lenient", default)]
+    time_description: String,
+    #[serde(rename = "JASMC", deserialize_with = "de::opt_string", default)]
+    room: Option<String>,
+    #[serde(rename = "ZWH", deserialize_with = "de::opt_string", default)]
+    seat: Option<String>,
+}
+
+/// 已安排的考试。
+#[derive(Deserialize, Debug, Clone, Eq, PartialEq, Getset2)]
+#[serde(from = "ExamRow")]
+#[getset2(get_ref(pub))]
+pub struct Exam {
+    semester: String,
+    course_name: String,
+    course_code: String,
+    /// 考试安排的标识。
+    arrangement_id: Option<String>,
+    /// 考试名称，如“期末考试”“补考”。
+    exam_name: Option<String>,
+    /// 教务系统给出的考试时间描述。
+    time_description: String,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    date: Option<NaiveDate>,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    start_time: Option<NaiveTime>,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    end_time: Option<NaiveTime>,
+    room: Option<String>,
+    seat: Option<String>,
+}
+
+/// 解析 `2024-01-08 14:00-16:00(星期一)`.
+fn parse_exam_time(s: &str) -> (Option<NaiveDate>, Option<NaiveTime>, Option<NaiveTime>) {
+    let mut parts = s.split_whitespace();
+    let date = parts
+        .next()
+        .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok());
+    let (start, end) = parts
+        .next()
+        .map(|t| t.split('(').next().unwrap_or(t))
+        .and_then(|t| t.split_once('-'))
+        .map_or((None, None), |(s, e)| {
+            (
+                NaiveTime::parse_from_str(s, "%H:%M").ok(),
+                NaiveTime::parse_from_str(e, "%H:%M").ok(),
+            )
+        });
+    (date, start, end)
+}
+impl From<ExamRow> for Exam {
+    fn from(row: ExamRow) -> Self {
+        let (date, start_time, end_time) = parse_exam_time(&row.time_description);
+        Self {
+            semester: row.semester,
+            course_name: row.course_name,
+            course_code: row.course_code,
+            arrangement_id: row.arrangement_id,
+            exam_name: row.exam_name,
+            time_description: row.time_description,
+            date,
+            start_time,
+            end_time,
+            room: row.room,
+            seat: row.seat,
+        }
+    }
+}
+
+/// 尚未安排考试的课程。
+#[derive(Deserialize, Debug, Clone, Eq, PartialEq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct UnscheduledExam {
+    #[serde(rename = "KCM", deserialize_with = "de::string_lenient", default)]
+    course_name: String,
+    #[serde(rename = "KCH", deserialize_with = "de::string_lenient", default)]
+    course_code: String,
+}
+
+#[derive(Debug, Clone, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct ExamSchedule {
+    semester: String,
+    exams: Vec<Exam>,
+    unscheduled: Vec<UnscheduledExam>,
+}
+
+impl Exam {
+    /// 时间无法解析的考试没有对应的事件。
+    pub fn to_ics_event(&self) -> Option<IcsEvent> {
+        let date = self.date?;
+        let description = [
+            self.seat.as_deref().map(|s| format!("座位号：{s}")),
+            Some(format!("课程号：{}", self.course_code)),
+            Some(self.time_description.clone()),
+        ];
+        // 同一课程可能有多场考试（期中、期末、补考），需以考试安排区分。
+        // 不含日期，考试改期后日历客户端会更新原有事件而不是新增一个。
+        let exam_key = self
+            .arrangement_id
+            .as_deref()
+            .or(self.exam_name.as_deref())
+            .unwrap_or("考试");
+        Some(IcsEvent {
+            uid: format!(
+                "{}-{}-{exam_key}-exam@x_l4rs",
+                self.semester, self.course_code
+            ),
+            summary: format!(
+                "{} {}",
+                self.course_name,
+                self.exam_name.as_deref().unwrap_or("考试")
+            ),
+            location: self.room.clone(),
+            description: Some(
+                description
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+            start: date.and_time(self.start_time?),
+            end: date.and_time(self.end_time?),
+        })
+    }
+}
+impl ExamSchedule {
+    /// 考试安排。
+    pub const APP_ID: &'static str = "4768687067472349";
+    pub const EXAMS_PATH: &'static str = "modules/wdksap/wdksap.do";
+    pub const UNSCHEDULED_PATH: &'static str = "modules/wdksap/cxyxkwapkwdkc.do";
+
+    pub fn fetch(app: &EhallApp, semester: &str) -> Result<Self, EhallError> {
+        let exams = app
+            .emap(Self::EXAMS_PATH)
+            .filter("XNXQDM", EmapBuilder::Equal, semester)
+            .order_by("KSRQ", SortOrder::Asc)
+            .order_by("KSSJMS", SortOrder::Asc)
+            .fetch_all()?;
+        let unscheduled = app
+            .emap(Self::UNSCHEDULED_PATH)
+            .param("XNXQDM", semester)
+            .fetch_all()?;
+        Ok(Self {
+            semester: semester.to_owned(),
+            exams,
+            unscheduled,
+        })
+    }
+    #[inline]
+    pub fn to_ics_events(&self) -> Vec<IcsEvent> {
+        self.exams.iter().filter_map(Exam::to_ics_event).collect()
+    }
+    #[inline]
+    pub fn to_ics(&self) -> String {
+        let mut calendar = IcsCalendar::new(&format!("考试 {}", self.semester));
+        calendar.extend(self.to_ics_events());
+        calendar.to_string()
+    }
+}
+impl EhallSession {
+    #[inline]
+    pub fn get_exam_schedule(&self, semester: &str) -> Result<ExamSchedule, EhallError> {
+        ExamSchedule::fetch(&self.open_app(ExamSchedule::APP_ID)?, semester)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exam() {
+        let exam: Exam = serde_json::from_str(
+            r#"{"XNXQDM":"2024-2025-1","KCM":"高等数学","KCH":"MA101","WID":"a1","KSMC":"期末考试","KSSJMS":"2025-01-08 14:00-16:00(星期三)","JASMC":"B-101","ZWH":"12"}"#,
+        )
+        .unwrap();
+        assert_eq!(exam.date(), NaiveDate::from_ymd_opt(2025, 1, 8));
+        assert_eq!(exam.end_time(), NaiveTime::from_hms_opt(16, 0, 0));
+        let event = exam.to_ics_event().unwrap();
+        assert_eq!(event.uid, "2024-2025-1-MA101-a1-exam@x_l4rs");
+        assert_eq!(event.summary, "高等数学 期末考试");
+        // 没有考试安排标识时，以考试名称区分同一课程的多场考试，改期后 UID 不变。
+        let retake = |time: &str| -> Exam {
+            serde_json::from_value(serde_json::json!({
+                "XNXQDM": "2024-2025-1", "KCM": "高等数学", "KCH": "MA101", "KSMC": "补考", "KSSJMS": time
+            }))
+            .unwrap()
+        };
+        let uid = retake("2025-03-01 09:00-11:00").to_ics_event().unwrap().uid;
+        assert_eq!(uid, "2024-2025-1-MA101-补考-exam@x_l4rs");
+        assert_eq!(
+            retake("2025-03-08 14:00-16:00").to_ics_event().unwrap().uid,
+            uid
+        );
+        assert_eq!(event.location.as_deref(), Some("B-101"));
+        assert_eq!(parse_exam_time("待定"), (None, None, None));
+    }
+}
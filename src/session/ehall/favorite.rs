@@ -0,0 +1,114 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{App, EhallSession, error::EhallError, session::ehall::parse_portal_data};
+use serde::Deserialize;
+
+/// 解析收藏操作的结果，`success` 与 `hasLogin` 均缺失时无法确认操作成功，视为解析失败。
+fn parse_ack(text: &str) -> Result<(), EhallError> {
+    #[derive(Deserialize)]
+    struct TmpData {
+        #[serde(rename = "hasLogin", default)]
+        has_login: Option<bool>,
+        #[serde(default)]
+        success: Option<bool>,
+        #[serde(default)]
+        msg: Option<String>,
+    }
+    let TmpData {
+        has_login,
+        success,
+        msg,
+    } = serde_json::from_str(text).map_err(|e| EhallError::ParseError(e.to_string()))?;
+    match (has_login, success) {
+        (Some(false), _) => Err(EhallError::NotLoggedIn),
+        (_, Some(false)) => Err(EhallError::ServerError(msg.unwrap_or_default())),
+        (None, None) => Err(EhallError::ParseError(format!(
+            "响应中没有 `success` 或 `hasLogin`：`{text}`."
+        ))),
+        _ => Ok(()),
+    }
+}
+
+impl EhallSession {
+    /// 收藏的应用，按首页上的顺序排列。
+    pub fn get_favorite_apps(&self) -> Result<Vec<App>, EhallError> {
+        let text = crate::protocol::ehall::get_favorite_apps(self)?
+            .into_body()
+            .read_to_string()?;
+        parse_portal_data(&text)
+    }
+    pub fn add_favorite_app(&self, app_id: &str) -> Result<(), EhallError> {
+        let text = crate::protocol::ehall::add_favorite_app(self, app_id)?
+            .into_body()
+            .read_to_string()?;
+        parse_ack(&text)
+    }
+    pub fn remove_favorite_app(&self, app_id: &str) -> Result<(), EhallError> {
+        let text = crate::protocol::ehall::remove_favorite_app(self, app_id)?
+            .into_body()
+            .read_to_string()?;
+        parse_ack(&text)
+    }
+    /// 按给定顺序重排收藏的应用，`app_ids` 应包含全部收藏的应用。
+    pub fn reorder_favorite_apps(&self, app_ids: &[&str]) -> Result<(), EhallError> {
+        let text = crate::protocol::ehall::sort_favorite_apps(self, app_ids)?
+            .into_body()
+            .read_to_string()?;
+        parse_ack(&text)
+    }
+    /// 将收藏设置为给定的应用及顺序，多余的收藏会被移除。
+    pub fn set_favorite_apps(&self, app_ids: &[&str]) -> Result<(), EhallError> {
+        let current = self.get_favorite_apps()?;
+        for app in &current {
+            if !app_ids.contains(&app.app_id().as_str()) {
+                self.remove_favorite_app(app.app_id())?;
+            }
+        }
+        for app_id in app_ids {
+            if !current.iter().any(|a| a.app_id() == app_id) {
+                self.add_favorite_app(app_id)?;
+            }
+        }
+        self.reorder_favorite_apps(app_ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ack() {
+        assert!(parse_ack(r#"{"hasLogin":true}"#).is_ok());
+        assert!(matches!(
+            parse_ack(r#"{"hasLogin":false}"#),
+            Err(EhallError::NotLoggedIn)
+        ));
+        assert!(matches!(
+            parse_ack(r#"{"hasLogin":true,"success":false,"msg":"应用不存在"}"#),
+            Err(EhallError::ServerError(_))
+        ));
+        assert!(parse_ack(r#"{"success":true}"#).is_ok());
+        assert!(matches!(parse_ack("{}"), Err(EhallError::ParseError(_))));
+    }
+}
@@ -0,0 +1,260 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{SectionTimes, Timetable};
+use chrono::{NaiveTime, TimeDelta};
+use getset2::Getset2;
+use std::ops::RangeInclusive;
+
+/// 共同空闲时段。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Getset2)]
+#[getset2(get_copy(pub))]
+pub struct FreeSlot {
+    week: u32,
+    /// 1 为周一，7 为周日。
+    weekday: u32,
+    start: NaiveTime,
+    end: NaiveTime,
+}
+impl FreeSlot {
+    #[inline]
+    pub fn length(&self) -> TimeDelta {
+        self.end - self.start
+    }
+}
+
+/// 空闲时段的查找条件。
+#[derive(Debug, Clone)]
+pub struct FreeTimeQuery<'a> {
+    pub section_times: &'a SectionTimes,
+    pub weekdays: RangeInclusive<u32>,
+    /// 短于该长度的空闲时段将被忽略。
+    pub min_length: TimeDelta,
+    /// 仅在这些时间窗口内查找，为空时为第一节上课至最后一节下课。
+    pub windows: Vec<(NaiveTime, NaiveTime)>,
+}
+impl<'a> FreeTimeQuery<'a> {
+    #[inline]
+    pub fn new(section_times: &'a SectionTimes) -> Self {
+        Self {
+            section_times,
+            weekdays: 1..=7,
+            min_length: TimeDelta::zero(),
+            windows: Vec::new(),
+        }
+    }
+    fn windows(&self) -> Vec<(NaiveTime, NaiveTime)> {
+        if !self.windows.is_empty() {
+            return self.windows.clone();
+        }
+        let last = self.section_times.len() as u32;
+        self.section_times.span(1, last).into_iter().collect()
+    }
+}
+
+/// 从 0 点到当天结束，用于无法确定上课时间的课程。
+const WHOLE_DAY: (NaiveTime, NaiveTime) = (
+    NaiveTime::MIN,
+    NaiveTime::MIN
+        .overflowing_sub_signed(TimeDelta::nanoseconds(1))
+        .0,
+);
+
+/// 从 `window` 中除去 `busy`, `busy` 需按开始时间排序。
+fn subtract(
+    (mut start, end): (NaiveTime, NaiveTime),
+    busy: &[(NaiveTime, NaiveTime)],
+) -> Vec<(NaiveTime, NaiveTime)> {
+    let mut free = Vec::new();
+    for &(b_start, b_end) in busy {
+        if b_end <= start || b_start >= end {
+            continue;
+        }
+        if b_start > start {
+            free.push((start, b_start));
+        }
+        start = start.max(b_end);
+    }
+    if start < end {
+        free.push((start, end));
+    }
+    free
+}
+
+/// 多个课表在第 `week` 周的共同空闲时段。
+///
+/// 节次不在 `query.section_times` 中的课程无法确定上课时间，当天视为全天有课。
+pub fn common_free_slots(
+    timetables: &[&Timetable],
+    week: u32,
+    query: &FreeTimeQuery,
+) -> Vec<FreeSlot> {
+    let windows = query.windows();
+    let mut slots = Vec::new();
+    for weekday in query.weekdays.clone() {
+        let mut busy = timetables
+            .iter()
+            .flat_map(|t| t.courses_on(week, weekday))
+            .map(|c| {
+                query
+                    .section_times
+                    .span(c.start_section(), c.end_section())
+                    .unwrap_or(WHOLE_DAY)
+            })
+            .collect::<Vec<_>>();
+        busy.sort();
+        for &window in &windows {
+            slots.extend(
+                subtract(window, &busy)
+                    .into_iter()
+                    .filter(|(s, e)| *e - *s >= query.min_length)
+                    .map(|(start, end)| FreeSlot {
+                        week,
+                        weekday,
+                        start,
+                        end,
+                    }),
+            );
+        }
+    }
+    slots
+}
+
+/// 多个课表在若干周内的共同空闲时段。
+#[inline]
+pub fn common_free_slots_in_weeks(
+    timetables: &[&Timetable],
+    weeks: RangeInclusive<u32>,
+    query: &FreeTimeQuery,
+) -> Vec<FreeSlot> {
+    weeks
+        .flat_map(|week| common_free_slots(timetables, week, query))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Course;
+
+    fn timetable(courses: &str) -> Timetable {
+        Timetable::new(
+            "2024-2025-1".to_owned(),
+            serde_json::from_str::<Vec<Course>>(courses).unwrap(),
+        )
+    }
+    #[test]
+    fn test_common_free_slots() {
+        let a = timetable(r#"[{"KCH":"A","SKXQ":1,"KSJC":1,"JSJC":2,"SKZC":"1"}]"#);
+        let b = timetable(
+            r#"[{"KCH":"B","SKXQ":1,"KSJC":3,"JSJC":4,"SKZC":"1"},{"KCH":"C","SKXQ":1,"KSJC":9,"JSJC":11,"SKZC":"1"}]"#,
+        );
+        let times = SectionTimes::xidian();
+        let t = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        let query = FreeTimeQuery {
+            weekdays: 1..=1,
+            min_length: TimeDelta::hours(1),
+            ..FreeTimeQuery::new(&times)
+        };
+        let slots = common_free_slots(&[&a, &b], 1, &query);
+        assert_eq!(slots.len(), 1);
+        assert_eq!((slots[0].start(), slots[0].end()), (t(12, 0), t(19, 0)));
+        let query = FreeTimeQuery {
+            windows: vec![(t(8, 0), t(12, 0))],
+            min_length: TimeDelta::zero(),
+            ..query
+        };
+        let slots = common_free_slots(&[&a, &b], 1, &query);
+        assert_eq!((slots[0].start(), slots[0].end()), (t(8, 0), t(8, 30)));
+        assert_eq!(
+            common_free_slots(&[&a, &b], 2, &query)[0].length(),
+            TimeDelta::hours(4)
+        );
+    }
+    #[test]
+    fn test_overlapping_busy() {
+        let a = timetable(
+            r#"[{"KCH":"A","SKXQ":2,"KSJC":1,"JSJC":3,"SKZC":"1"},{"KCH":"D","SKXQ":2,"KSJC":6,"JSJC":7,"SKZC":"1"}]"#,
+        );
+        let b = timetable(
+            r#"[{"KCH":"B","SKXQ":2,"KSJC":2,"JSJC":4,"SKZC":"1"},{"KCH":"C","SKXQ":2,"KSJC":5,"JSJC":6,"SKZC":"1"},{"KCH":"E","SKXQ":2,"KSJC":5,"JSJC":5,"SKZC":"1"}]"#,
+        );
+        let times = SectionTimes::xidian();
+        let t = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        let query = FreeTimeQuery {
+            weekdays: 2..=2,
+            ..FreeTimeQuery::new(&times)
+        };
+        let slots = common_free_slots(&[&a, &b], 1, &query)
+            .into_iter()
+            .map(|s| (s.start(), s.end()))
+            .collect::<Vec<_>>();
+        assert_eq!(slots, vec![(t(12, 0), t(14, 0)), (t(16, 40), t(21, 30))]);
+    }
+    #[test]
+    fn test_unmapped_sections() {
+        // 第 12 节不在 `SectionTimes::xidian()` 中。
+        let a = timetable(r#"[{"KCH":"A","SKXQ":1,"KSJC":12,"JSJC":13,"SKZC":"1"}]"#);
+        let times = SectionTimes::xidian();
+        let query = FreeTimeQuery {
+            weekdays: 1..=2,
+            ..FreeTimeQuery::new(&times)
+        };
+        let slots = common_free_slots(&[&a], 1, &query);
+        assert!(slots.iter().all(|s| s.weekday() == 2));
+        assert_eq!(slots.len(), 1);
+    }
+    #[test]
+    fn test_min_length_across_windows() {
+        let a = timetable(
+            r#"[{"KCH":"A","SKXQ":1,"KSJC":1,"JSJC":2,"SKZC":"1"},{"KCH":"B","SKXQ":1,"KSJC":7,"JSJC":8,"SKZC":"1"},{"KCH":"C","SKXQ":2,"KSJC":9,"JSJC":10,"SKZC":"1"}]"#,
+        );
+        let times = SectionTimes::xidian();
+        let t = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        let query = FreeTimeQuery {
+            weekdays: 1..=2,
+            min_length: TimeDelta::hours(1),
+            windows: vec![
+                (t(8, 0), t(12, 0)),
+                (t(14, 0), t(18, 0)),
+                (t(19, 0), t(22, 0)),
+            ],
+            ..FreeTimeQuery::new(&times)
+        };
+        let slots = common_free_slots(&[&a], 1, &query)
+            .into_iter()
+            .map(|s| (s.weekday(), s.start(), s.end()))
+            .collect::<Vec<_>>();
+        // 周一 8:00-8:30 与 17:30-18:00 短于一小时，被忽略。
+        assert_eq!(
+            slots,
+            vec![
+                (1, t(10, 5), t(12, 0)),
+                (1, t(14, 0), t(15, 55)),
+                (1, t(19, 0), t(22, 0)),
+                (2, t(8, 0), t(12, 0)),
+                (2, t(14, 0), t(18, 0)),
+                (2, t(20, 40), t(22, 0)),
+            ]
+        );
+    }
+}
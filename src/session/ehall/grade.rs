@@ -0,0 +1,319 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{EhallApp, EhallSession, EmapBuilder, SortOrder, error::EhallError, utils::de};
+use getset2::Getset2;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// 成绩，可能是百分制分数，也可能是等级。
+#[derive(Debug, Clone, PartialEq)]
+pub enum Score {
+    Numeric(f64),
+    Level(String),
+}
+impl Score {
+    fn parse(s: &str) -> Self {
+        match s.trim().parse() {
+            Ok(n) => Score::Numeric(n),
+            Err(_) => Score::Level(s.trim().to_owned()),
+        }
+    }
+    /// 等级制成绩按 优秀 95、良好 85、中等 75、及格 65、不及格 0 折算为百分制。
+    pub fn to_numeric(&self) -> Option<f64> {
+        match self {
+            Score::Numeric(n) => Some(*n),
+            Score::Level(l) => match l.as_str() {
+                "优秀" | "优" | "A" => Some(95.0),
+                "良好" | "良" | "B" => Some(85.0),
+                "中等" | "中" | "C" => Some(75.0),
+                "及格" | "合格" | "通过" | "D" => Some(65.0),
+                "不及格" | "不合格" | "不通过" | "F" => Some(0.0),
+                _ => None,
+            },
+        }
+    }
+    /// 折算后不低于 60 分视为通过。
+    #[inline]
+    pub fn is_passing(&self) -> bool {
+        self.to_numeric().is_some_and(|n| n >= 60.0)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CourseNature {
+    Required,
+    Elective,
+    Other,
+}
+impl CourseNature {
+    #[inline]
+    pub fn from_display(s: &str) -> Self {
+        if s.contains("必修") {
+            CourseNature::Required
+        } else if s.contains('选') {
+            CourseNature::Elective
+        } else {
+            CourseNature::Other
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct GradeRow {
+    #[serde(rename = "XNXQDM", deserialize_with = "de::string_lenient", default)]
+    semester: String,
+    #[serde(rename = "KCM", deserialize_with = "de::string_lenient", default)]
+    course_name: String,
+    #[serde(rename = "KCH", deserialize_with = "de::string_lenient", default)]
+    course_code: String,
+    #[serde(rename = "XF", deserialize_with = "de::f64_lenient", default)]
+    credit: f64,
+    #[serde(rename = "ZCJ", deserialize_with = "de::string_lenient", default)]
+    score: String,
+    #[serde(
+        rename = "KCXZDM_DISPLAY",
+        deserialize_with = "de::string_lenient",
+        default
+    )]
+    nature: String,
+    #[serde(
+        rename = "KSLXDM_DISPLAY",
+        deserialize_with = "de::string_lenient",
+        default
+    )]
+    exam_type: String,
+    #[serde(
+        rename = "CXCKDM_DISPLAY",
+        deserialize_with = "de::string_lenient",
+        default
+    )]
+    retake: String,
+    #[serde(rename = "XFJD", deserialize_with = "de::opt_f64_lenient", default)]
+    grade_point: Option<f64>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Getset2)]
+#[serde(from = "GradeRow")]
+#[getset2(get_ref(pub))]
+pub struct Grade {
+    semester: String,
+    course_name: String,
+    course_code: String,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    credit: f64,
+    score: Score,
+    /// 课程性质，如“必修”“选修”。
+    nature: String,
+    /// 考试类型，如“正常考试”“补考”。
+    exam_type: String,
+    /// 是否为重修。
+    #[getset2(get_copy(pub), skip(get_ref))]
+    is_retake: bool,
+    /// 教务系统给出的绩点。
+    #[getset2(get_copy(pub), skip(get_ref))]
+    grade_point: Option<f64>,
+}
+impl Grade {
+    #[inline]
+    pub fn course_nature(&self) -> CourseNature {
+        CourseNature::from_display(&self.nature)
+    }
+}
+impl From<GradeRow> for Grade {
+    fn from(row: GradeRow) -> Self {
+        Self {
+            score: Score::parse(&row.score),
+            is_retake: row.retake.contains("重修"),
+            semester: row.semester,
+            course_name: row.course_name,
+            course_code: row.course_code,
+            credit: row.credit,
+            nature: row.nature,
+            exam_type: row.exam_type,
+            grade_point: row.grade_point,
+        }
+    }
+}
+
+/// 绩点计算方式。
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum GpaScheme {
+    /// 90 分以上 4.0, 80 分以上 3.0, 70 分以上 2.0, 60 分以上 1.0.
+    Standard4,
+    /// 西电的绩点换算表。
+    #[default]
+    Xidian,
+    /// `(最低分数, 绩点)` 列表，取第一个不高于分数的项，均不满足时为 0.
+    Custom(Vec<(f64, f64)>),
+}
+impl GpaScheme {
+    const STANDARD_4: [(f64, f64); 4] = [(90.0, 4.0), (80.0, 3.0), (70.0, 2.0), (60.0, 1.0)];
+    const XIDIAN: [(f64, f64); 11] = [
+        (95.0, 4.0),
+        (90.0, 3.9),
+        (84.0, 3.8),
+        (80.0, 3.6),
+        (76.0, 3.4),
+        (73.0, 3.2),
+        (70.0, 3.0),
+        (67.0, 2.7),
+        (64.0, 2.4),
+        (62.0, 2.2),
+        (60.0, 2.0),
+    ];
+    pub fn grade_point(&self, score: f64) -> f64 {
+        let table: &[(f64, f64)] = match self {
+            GpaScheme::Standard4 => &Self::STANDARD_4,
+            GpaScheme::Xidian => &Self::XIDIAN,
+            GpaScheme::Custom(table) => table,
+        };
+        table
+            .iter()
+            .find(|(min, _)| score >= *min)
+            .map_or(0.0, |(_, point)| *point)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct GradeFilter<'a> {
+    /// 为空时不限学期。
+    pub semesters: &'a [&'a str],
+    /// 为 `None` 时不限课程性质。
+    pub nature: Option<CourseNature>,
+}
+impl GradeFilter<'_> {
+    #[inline]
+    pub fn matches(&self, grade: &Grade) -> bool {
+        (self.semesters.is_empty() || self.semesters.contains(&grade.semester.as_str()))
+            && self.nature.is_none_or(|n| n == grade.course_nature())
+    }
+}
+
+/// 按过滤条件筛选，同一课程有多条记录（补考、重修）时取最高分。无法折算为分数的记录将被忽略。
+fn effective_scores<'a>(grades: &'a [Grade], filter: &GradeFilter) -> Vec<(&'a Grade, f64)> {
+    let mut best: HashMap<&str, (&Grade, f64)> = HashMap::new();
+    for g in grades.iter().filter(|g| filter.matches(g)) {
+        let Some(score) = g.score.to_numeric() else {
+            continue;
+        };
+        best.entry(&g.course_code)
+            .and_modify(|e| {
+                if score > e.1 {
+                    *e = (g, score)
+                }
+            })
+            .or_insert((g, score));
+    }
+    best.into_values().collect()
+}
+fn credit_weighted(values: impl Iterator<Item = (f64, f64)>) -> Option<f64> {
+    let (sum, credits) = values.fold((0.0, 0.0), |(s, c), (v, credit)| {
+        (s + v * credit, c + credit)
+    });
+    (credits > 0.0).then(|| sum / credits)
+}
+/// 学分加权平均绩点。
+pub fn gpa(grades: &[Grade], scheme: &GpaScheme, filter: &GradeFilter) -> Option<f64> {
+    credit_weighted(
+        effective_scores(grades, filter)
+            .into_iter()
+            .map(|(g, s)| (scheme.grade_point(s), g.credit)),
+    )
+}
+/// 学分加权平均分。
+pub fn weighted_average(grades: &[Grade], filter: &GradeFilter) -> Option<f64> {
+    credit_weighted(
+        effective_scores(grades, filter)
+            .into_iter()
+            .map(|(g, s)| (s, g.credit)),
+    )
+}
+
+impl Grade {
+    /// 成绩查询。
+    pub const APP_ID: &'static str = "4768574631264620";
+    pub const GRADES_PATH: &'static str = "modules/cjcx/xscjcx.do";
+
+    pub fn fetch(app: &EhallApp, semester: Option<&str>) -> Result<Vec<Grade>, EhallError> {
+        let mut query = app
+            .emap(Self::GRADES_PATH)
+            .order_by("XNXQDM", SortOrder::Desc)
+            .order_by("KCH", SortOrder::Asc);
+        if let Some(semester) = semester {
+            query = query.filter("XNXQDM", EmapBuilder::Equal, semester);
+        }
+        query.fetch_all()
+    }
+}
+impl EhallSession {
+    /// 获取成绩，`semester` 为 `None` 时获取所有学期。
+    #[inline]
+    pub fn get_grades(&self, semester: Option<&str>) -> Result<Vec<Grade>, EhallError> {
+        Grade::fetch(&self.open_app(Grade::APP_ID)?, semester)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grade(code: &str, credit: f64, score: &str, nature: &str, semester: &str) -> Grade {
+        Grade::from(GradeRow {
+            semester: semester.to_owned(),
+            course_name: code.to_owned(),
+            course_code: code.to_owned(),
+            credit,
+            score: score.to_owned(),
+            nature: nature.to_owned(),
+            exam_type: "正常考试".to_owned(),
+            retake: "初修".to_owned(),
+            grade_point: None,
+        })
+    }
+    #[test]
+    fn test_gpa() {
+        let grades = vec![
+            grade("A", 4.0, "96", "必修", "2024-2025-1"),
+            grade("B", 2.0, "良好", "选修", "2024-2025-1"),
+            grade("C", 2.0, "55", "必修", "2024-2025-2"),
+            grade("C", 2.0, "81", "必修", "2024-2025-2"),
+        ];
+        let all = GradeFilter::default();
+        let gpa_x = gpa(&grades, &GpaScheme::Xidian, &all).unwrap();
+        assert!((gpa_x - (4.0 * 4.0 + 3.8 * 2.0 + 3.6 * 2.0) / 8.0).abs() < 1e-9);
+        let gpa_s = gpa(&grades, &GpaScheme::Standard4, &all).unwrap();
+        assert!((gpa_s - (4.0 * 4.0 + 3.0 * 2.0 + 3.0 * 2.0) / 8.0).abs() < 1e-9);
+        let required = GradeFilter {
+            nature: Some(CourseNature::Required),
+            ..Default::default()
+        };
+        let avg = weighted_average(&grades, &required).unwrap();
+        assert!((avg - (96.0 * 4.0 + 81.0 * 2.0) / 6.0).abs() < 1e-9);
+        let custom = GpaScheme::Custom(vec![(60.0, 1.0)]);
+        let semester = GradeFilter {
+            semesters: &["2024-2025-2"],
+            nature: None,
+        };
+        assert_eq!(gpa(&grades, &custom, &semester), Some(1.0));
+    }
+}
@@ -0,0 +1,248 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{EhallSession, error::EhallError};
+use log::{debug, warn};
+use ureq::{Body, ResponseExt, http::Response};
+
+/// 应用作用域相关的 Cookie.
+pub const EHALL_APP_SCOPE_COOKIES: [&str; 2] = ["_WEU", "GS_SESSIONID"];
+
+/// 将 `appShow` 重定向落地的地址拆分为 `(origin, base_url)`.
+///
+/// 如 `https://ehall.xidian.edu.cn/jwapp/sys/wdkb/*default/index.do?t_s=1`
+/// 拆分为 `https://ehall.xidian.edu.cn` 与 `https://ehall.xidian.edu.cn/jwapp/sys/wdkb`.
+fn split_app_url(url: &str) -> Option<(&str, &str)> {
+    let url = url.split(['?', '#']).next()?;
+    let path_s = url.find("://")? + 3;
+    let path_s = path_s + url[path_s..].find('/')?;
+    let path = &url[path_s..];
+    if path.contains("/authserver/") || path.ends_with("/login") {
+        return None;
+    }
+    let base_e = match path.find("/*default") {
+        Some(e) => e,
+        None => path.rfind('/')?,
+    };
+    Some((&url[..path_s], &url[..path_s + base_e]))
+}
+
+/// 请求最终落地的 `host` 与 `path` 是否表明作用域已失效：
+/// 离开了应用所在的主机（如被重定向到统一认证），或落在认证与门户的登录页上。
+///
+/// 仅比较主机，`http` 与 `https` 之间的跳转不视为失效。
+fn is_login_landing(origin: &str, host: &str, path: &str) -> bool {
+    let origin_host = origin.split_once("://").map_or(origin, |(_, h)| h);
+    let origin_host = origin_host.split(':').next().unwrap_or_default();
+    !host.eq_ignore_ascii_case(origin_host)
+        || path.contains("/authserver/")
+        || path == "/login"
+        || path.ends_with("/login.html")
+}
+
+/// 已进入的 ehall 应用。
+///
+/// 应用内的请求需要带上进入应用时下发的 `_WEU` 等 Cookie, 这些 Cookie 过期后需要重新进入应用。
+/// 通过该结构发出的请求会在检测到作用域失效时自动重新进入一次。
+pub struct EhallApp<'a> {
+    session: &'a EhallSession,
+    app_id: String,
+    origin: String,
+    base_url: String,
+}
+impl<'a> EhallApp<'a> {
+    pub(crate) fn open(session: &'a EhallSession, app_id: &str) -> Result<Self, EhallError> {
+        let r = session.use_app(app_id)?;
+        let uri = r.get_uri().to_string();
+        debug!("{uri}");
+        let (origin, base_url) = split_app_url(&uri).ok_or(EhallError::NotLoggedIn)?;
+        Ok(Self {
+            session,
+            app_id: app_id.to_owned(),
+            origin: origin.to_owned(),
+            base_url: base_url.to_owned(),
+        })
+    }
+    #[inline]
+    pub fn session(&self) -> &'a EhallSession {
+        self.session
+    }
+    #[inline]
+    pub fn app_id(&self) -> &str {
+        &self.app_id
+    }
+    /// 应用的根地址，如 `https://ehall.xidian.edu.cn/jwapp/sys/wdkb`.
+    #[inline]
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+    #[inline]
+    pub fn origin(&self) -> &str {
+        &self.origin
+    }
+    /// 当前会话中应用作用域相关的 Cookie.
+    pub fn scope_cookies(&self) -> Vec<(String, String)> {
+        self.session
+            .cookie_jar_lock()
+            .iter()
+            .filter(|c| EHALL_APP_SCOPE_COOKIES.contains(&c.name()))
+            .map(|c| (c.name().to_owned(), c.value().to_owned()))
+            .collect()
+    }
+    /// 以 `/` 开头的路径相对于 `origin`, 其余相对于 `base_url`, 完整地址原样返回。
+    pub fn url(&self, path: &str) -> String {
+        if path.starts_with("http://") || path.starts_with("https://") {
+            path.to_owned()
+        } else if path.starts_with('/') {
+            format!("{}{path}", self.origin)
+        } else {
+            format!("{}/{path}", self.base_url)
+        }
+    }
+    /// 重新进入应用，刷新作用域 Cookie.
+    #[inline]
+    pub fn reenter(&self) -> Result<(), EhallError> {
+        self.session.use_app(&self.app_id)?;
+        Ok(())
+    }
+    fn is_scope_expired(&self, r: &Result<Response<Body>, ureq::Error>) -> bool {
+        match r {
+            Ok(r) => {
+                let uri = r.get_uri();
+                is_login_landing(&self.origin, uri.host().unwrap_or_default(), uri.path())
+            }
+            Err(ureq::Error::StatusCode(code)) => *code == 401 || *code == 403,
+            Err(_) => false,
+        }
+    }
+    fn call_in_scope(
+        &self,
+        call: impl Fn() -> Result<Response<Body>, ureq::Error>,
+    ) -> Result<Response<Body>, EhallError> {
+        let r = call();
+        if !self.is_scope_expired(&r) {
+            return Ok(r?);
+        }
+        warn!("应用 `{}` 的作用域已失效，重新进入。", self.app_id);
+        self.reenter()?;
+        let r = call();
+        if self.is_scope_expired(&r) {
+            Err(EhallError::NotLoggedIn)
+        } else {
+            Ok(r?)
+        }
+    }
+    pub fn get(&self, path: &str) -> Result<Response<Body>, EhallError> {
+        let url = self.url(path);
+        self.call_in_scope(|| self.session.get(&url).call())
+    }
+    pub fn post_form(
+        &self,
+        path: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response<Body>, EhallError> {
+        let url = self.url(path);
+        self.call_in_scope(|| {
+            self.session
+                .post(&url)
+                .header("X-Requested-With", "XMLHttpRequest")
+                .send_form(form.iter().copied())
+        })
+    }
+}
+
+impl EhallSession {
+    /// 进入应用，返回应用的句柄。
+    #[inline]
+    pub fn open_app(&self, app_id: &str) -> Result<EhallApp<'_>, EhallError> {
+        EhallApp::open(self, app_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_app_url() {
+        assert_eq!(
+            split_app_url("https://ehall.xidian.edu.cn/jwapp/sys/wdkb/*default/index.do?t_s=1"),
+            Some((
+                "https://ehall.xidian.edu.cn",
+                "https://ehall.xidian.edu.cn/jwapp/sys/wdkb"
+            ))
+        );
+        assert_eq!(
+            split_app_url("http://ehall.xidian.edu.cn/xsfw/sys/swmxsxxapp/index.do#/"),
+            Some((
+                "http://ehall.xidian.edu.cn",
+                "http://ehall.xidian.edu.cn/xsfw/sys/swmxsxxapp"
+            ))
+        );
+        assert_eq!(
+            split_app_url("https://ids.xidian.edu.cn/authserver/login?service=x"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_is_login_landing() {
+        let origin = "https://ehall.xidian.edu.cn";
+        // 相对于 `base_url` 与以 `/` 开头、相对于 `origin` 的路径都不视为失效。
+        assert!(!is_login_landing(
+            origin,
+            "ehall.xidian.edu.cn",
+            "/jwapp/sys/wdkb/modules/xskcb/xskcb.do"
+        ));
+        assert!(!is_login_landing(
+            origin,
+            "ehall.xidian.edu.cn",
+            "/jwapp/sys/funauthapp/api/getAppConfig/wdkb.do"
+        ));
+        assert!(!is_login_landing(
+            "http://ehall.xidian.edu.cn",
+            "ehall.xidian.edu.cn",
+            "/xsfw/sys/jbxxapp/index.do"
+        ));
+        let session = EhallSession {
+            agent: crate::utils::build_agent(),
+        };
+        let app = EhallApp {
+            session: &session,
+            app_id: "4770397878132218".to_owned(),
+            origin: origin.to_owned(),
+            base_url: format!("{origin}/jwapp/sys/wdkb"),
+        };
+        let url: ureq::http::Uri = app.url("/jwapp/sys/funauthapp/api/x.do").parse().unwrap();
+        assert!(!is_login_landing(
+            app.origin(),
+            url.host().unwrap(),
+            url.path()
+        ));
+        assert!(is_login_landing(
+            origin,
+            "ids.xidian.edu.cn",
+            "/authserver/login"
+        ));
+        assert!(is_login_landing(origin, "ehall.xidian.edu.cn", "/login"));
+    }
+}
@@ -0,0 +1,123 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    EhallSession,
+    error::EhallError,
+    session::ehall::{PORTAL_PAGE_SIZE, fetch_portal_pages, parse_portal_data},
+    utils::de,
+};
+use chrono::NaiveDateTime;
+use getset2::Getset2;
+use serde::Deserialize;
+
+/// 待办事项。
+#[derive(Deserialize, Debug, Clone, Eq, PartialEq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct TodoItem {
+    #[serde(deserialize_with = "de::string_lenient", default)]
+    id: String,
+    /// 来源应用。
+    #[serde(rename = "appName", deserialize_with = "de::string_lenient", default)]
+    app_name: String,
+    #[serde(deserialize_with = "de::string_lenient", default)]
+    title: String,
+    #[serde(
+        rename = "createTime",
+        deserialize_with = "de::opt_datetime_lenient",
+        default
+    )]
+    #[getset2(get_copy(pub), skip(get_ref))]
+    time: Option<NaiveDateTime>,
+    /// 办理地址。
+    #[serde(deserialize_with = "de::opt_string", default)]
+    url: Option<String>,
+}
+
+/// 消息中心的消息。
+#[derive(Deserialize, Debug, Clone, Eq, PartialEq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct Message {
+    #[serde(deserialize_with = "de::string_lenient", default)]
+    id: String,
+    #[serde(rename = "appName", deserialize_with = "de::string_lenient", default)]
+    app_name: String,
+    #[serde(deserialize_with = "de::string_lenient", default)]
+    title: String,
+    #[serde(deserialize_with = "de::opt_string", default)]
+    content: Option<String>,
+    #[serde(
+        rename = "sendTime",
+        deserialize_with = "de::opt_datetime_lenient",
+        default
+    )]
+    #[getset2(get_copy(pub), skip(get_ref))]
+    time: Option<NaiveDateTime>,
+    #[serde(rename = "isRead", deserialize_with = "de::bool_lenient", default)]
+    #[getset2(get_copy(pub), skip(get_ref))]
+    is_read: bool,
+    #[serde(deserialize_with = "de::opt_string", default)]
+    url: Option<String>,
+}
+
+impl EhallSession {
+    pub fn get_todo_items(&self) -> Result<Vec<TodoItem>, EhallError> {
+        fetch_portal_pages(|n| crate::protocol::ehall::get_todo_tasks(self, n, PORTAL_PAGE_SIZE))
+    }
+    pub fn get_messages(&self) -> Result<Vec<Message>, EhallError> {
+        fetch_portal_pages(|n| crate::protocol::ehall::get_messages(self, n, PORTAL_PAGE_SIZE))
+    }
+    #[inline]
+    pub fn get_unread_messages(&self) -> Result<Vec<Message>, EhallError> {
+        let mut messages = self.get_messages()?;
+        messages.retain(|m| !m.is_read);
+        Ok(messages)
+    }
+    pub fn mark_messages_read(&self, message_ids: &[&str]) -> Result<(), EhallError> {
+        if message_ids.is_empty() {
+            return Ok(());
+        }
+        let text = crate::protocol::ehall::read_messages(self, message_ids)?
+            .into_body()
+            .read_to_string()?;
+        parse_portal_data::<serde_json::Value>(&text).map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message() {
+        let messages: Vec<Message> = parse_portal_data(
+            r#"{"hasLogin":true,"data":[{"id":101,"appName":"请假","title":"审核通过","sendTime":"2024-10-01 12:00:00","isRead":"0","url":"http://ehall.xidian.edu.cn/x"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(messages[0].id(), "101");
+        assert!(!messages[0].is_read());
+        assert_eq!(
+            messages[0].time().map(|t| t.to_string()).as_deref(),
+            Some("2024-10-01 12:00:00")
+        );
+    }
+}
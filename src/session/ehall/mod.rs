@@ -20,18 +20,52 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+mod app;
+mod app_cache;
+mod calendar;
+mod catalogue;
+mod classroom;
+mod emap;
+mod exam;
+mod favorite;
+mod free_time;
+mod grade;
+mod handle;
+mod message;
+mod plan;
+mod process;
+mod role;
+mod student;
+mod timetable;
+
+pub use app::*;
+pub use app_cache::*;
+pub use calendar::*;
+pub use catalogue::*;
+pub use classroom::*;
+pub use emap::*;
+pub use exam::*;
+pub use free_time::*;
+pub use grade::*;
+pub use handle::*;
+pub use message::*;
+pub use plan::*;
+pub use process::*;
+pub use role::*;
+pub use student::*;
+pub use timetable::*;
+
 use crate::{
     IDSLoginImpl, XL4rsSessionTrait,
-    error::{CaptchaError, LoginError},
+    error::{CaptchaError, EhallError, LoginError},
 };
-use getset2::Getset2;
 use image::DynamicImage;
-use serde::Deserialize;
 use std::ops::Deref;
 use ureq::{Agent, Body, http::Response};
 
 pub struct EhallLoginImpl {
     inner: IDSLoginImpl,
+    role: Option<String>,
 }
 
 impl EhallLoginImpl {
@@ -39,13 +73,45 @@ impl EhallLoginImpl {
     pub fn new() -> Self {
         EhallLoginImpl {
             inner: IDSLoginImpl::TARGET_EHALL,
+            role: None,
         }
     }
+    /// 登录后切换到指定身份，`role` 为身份的 id 或名称，见 [`EhallSession::get_roles`].
+    #[inline]
+    pub fn with_role(mut self, role: &str) -> Self {
+        self.role = Some(role.to_owned());
+        self
+    }
+    #[inline]
+    pub fn role(&self) -> Option<&str> {
+        self.role.as_deref()
+    }
+    /// 统一身份认证登录后，若指定了身份则切换至该身份。
+    fn login_with_role(
+        &self,
+        agent: Agent,
+        account: &str,
+        passwd: &[u8],
+        captcha_solver: &impl Fn(&DynamicImage, &DynamicImage) -> Result<u32, CaptchaError>,
+    ) -> Result<EhallSession, LoginError> {
+        self.inner.login(&agent, account, passwd, captcha_solver)?;
+        let session = EhallSession { agent };
+        if let Some(role) = &self.role {
+            session.switch_role(role).map_err(|e| match e {
+                EhallError::AgentError(e) => LoginError::AgentError(e),
+                e => LoginError::ServerError(e.to_string()),
+            })?;
+        }
+        Ok(session)
+    }
 }
 impl From<IDSLoginImpl> for EhallLoginImpl {
     #[inline]
     fn from(value: IDSLoginImpl) -> Self {
-        Self { inner: value }
+        Self {
+            inner: value,
+            role: None,
+        }
     }
 }
 impl Default for EhallLoginImpl {
@@ -74,20 +140,6 @@ impl Deref for EhallSession {
         &self.agent
     }
 }
-// TODO: non_exhaustive fields.
-#[derive(Deserialize, Debug, Getset2)]
-#[getset2(get_ref(pub))]
-pub struct App {
-    #[serde(rename = "appId")]
-    app_id: String,
-    #[serde(rename = "appName")]
-    app_name: String,
-    #[serde(rename = "middleIcon")]
-    middle_icon: String,
-    #[serde(rename = "type")]
-    app_type: i32,
-    description: Option<String>,
-}
 impl EhallSession {
     #[inline]
     pub fn login_with_user_agent(
@@ -98,8 +150,7 @@ impl EhallSession {
         captcha_solver: &impl Fn(&DynamicImage, &DynamicImage) -> Result<u32, CaptchaError>,
     ) -> Result<Self, LoginError> {
         let agent = crate::utils::build_agent_with_user_agent(ua);
-        login_impl.login(&agent, account, passwd, captcha_solver)?;
-        Ok(EhallSession { agent })
+        login_impl.login_with_role(agent, account, passwd, captcha_solver)
     }
     #[inline]
     pub fn login(
@@ -109,31 +160,12 @@ impl EhallSession {
         captcha_solver: &impl Fn(&DynamicImage, &DynamicImage) -> Result<u32, CaptchaError>,
     ) -> Result<Self, LoginError> {
         let agent = crate::utils::build_agent();
-        login_impl.login(&agent, account, passwd, captcha_solver)?;
-        Ok(EhallSession { agent })
+        login_impl.login_with_role(agent, account, passwd, captcha_solver)
     }
     #[inline]
     pub fn use_app(&self, app_id: &str) -> Result<Response<Body>, Box<ureq::Error>> {
         crate::protocol::ehall::use_app(self, app_id)
     }
-    pub fn get_app_list(
-        &self,
-        search_key: &str,
-    ) -> Result<Vec<serde_json::Value>, Box<ureq::Error>> {
-        #[derive(Deserialize)]
-        struct TmpData {
-            #[serde(rename = "hasLogin")]
-            has_login: bool,
-            data: Vec<serde_json::Value>,
-        }
-        let r = crate::protocol::ehall::get_app_list(self, search_key)?;
-        #[cfg(debug_assertions)]
-        let TmpData { has_login, data } = crate::utils::time_it_and_print_result(|| {
-            r.into_body().read_json().expect("failed to parse json.")
-        });
-        assert!(has_login);
-        Ok(data)
-    }
 }
 impl XL4rsSessionTrait for EhallSession {
     #[inline]
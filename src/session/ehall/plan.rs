@@ -0,0 +1,236 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{EhallApp, EhallSession, Grade, SortOrder, error::EhallError, utils::de};
+use getset2::Getset2;
+use serde::Deserialize;
+use std::collections::HashSet;
+
+/// 培养方案中的课程。
+#[derive(Deserialize, Debug, Clone, PartialEq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct PlanCourse {
+    #[serde(rename = "KZH", deserialize_with = "de::string_lenient", default)]
+    group_id: String,
+    #[serde(rename = "KCH", deserialize_with = "de::string_lenient", default)]
+    course_code: String,
+    #[serde(rename = "KCM", deserialize_with = "de::string_lenient", default)]
+    course_name: String,
+    #[serde(rename = "XF", deserialize_with = "de::f64_lenient", default)]
+    #[getset2(get_copy(pub), skip(get_ref))]
+    credit: f64,
+    /// 是否必修。
+    #[serde(rename = "SFBX", deserialize_with = "de::bool_lenient", default)]
+    #[getset2(get_copy(pub), skip(get_ref))]
+    is_mandatory: bool,
+}
+
+/// 课程组（模块），可以嵌套。
+#[derive(Deserialize, Debug, Clone, PartialEq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct CourseGroup {
+    #[serde(rename = "KZH", deserialize_with = "de::string_lenient", default)]
+    id: String,
+    #[serde(rename = "KZM", deserialize_with = "de::string_lenient", default)]
+    name: String,
+    /// 上级课程组，顶层为 `None`.
+    #[serde(rename = "FKZH", deserialize_with = "de::opt_string", default)]
+    parent_id: Option<String>,
+    /// 最低学分要求。
+    #[serde(rename = "ZSXF", deserialize_with = "de::f64_lenient", default)]
+    #[getset2(get_copy(pub), skip(get_ref))]
+    min_credits: f64,
+}
+
+/// 培养方案。
+#[derive(Debug, Clone, PartialEq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct TrainingPlan {
+    groups: Vec<CourseGroup>,
+    courses: Vec<PlanCourse>,
+}
+
+/// 课程组的学分审核结果。
+#[derive(Debug, Clone, PartialEq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct GroupAudit {
+    group_id: String,
+    group_name: String,
+    parent_id: Option<String>,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    required_credits: f64,
+    /// 含下级课程组的已获学分。
+    #[getset2(get_copy(pub), skip(get_ref))]
+    earned_credits: f64,
+    /// 本组中尚未通过的必修课。
+    outstanding_mandatory: Vec<PlanCourse>,
+}
+impl GroupAudit {
+    #[inline]
+    pub fn is_satisfied(&self) -> bool {
+        self.earned_credits >= self.required_credits && self.outstanding_mandatory.is_empty()
+    }
+}
+
+/// 毕业学分审核结果。
+#[derive(Debug, Clone, PartialEq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct CreditAudit {
+    groups: Vec<GroupAudit>,
+}
+impl CreditAudit {
+    /// 顶层课程组的学分要求之和。
+    #[inline]
+    pub fn total_required(&self) -> f64 {
+        self.top_level().map(|g| g.required_credits).sum()
+    }
+    #[inline]
+    pub fn total_earned(&self) -> f64 {
+        self.top_level().map(|g| g.earned_credits).sum()
+    }
+    #[inline]
+    fn top_level(&self) -> impl Iterator<Item = &GroupAudit> {
+        self.groups.iter().filter(|g| g.parent_id.is_none())
+    }
+    /// 所有未通过的必修课。
+    #[inline]
+    pub fn outstanding_mandatory(&self) -> impl Iterator<Item = &PlanCourse> {
+        self.groups
+            .iter()
+            .flat_map(|g| g.outstanding_mandatory.iter())
+    }
+    #[inline]
+    pub fn is_satisfied(&self) -> bool {
+        self.groups.iter().all(GroupAudit::is_satisfied)
+    }
+}
+
+impl TrainingPlan {
+    /// 我的培养方案。
+    pub const APP_ID: &'static str = "4766859234543231";
+    pub const GROUPS_PATH: &'static str = "modules/pyfa/kzcx.do";
+    pub const COURSES_PATH: &'static str = "modules/pyfa/kzkccx.do";
+
+    #[inline]
+    pub fn new(groups: Vec<CourseGroup>, courses: Vec<PlanCourse>) -> Self {
+        Self { groups, courses }
+    }
+    pub fn fetch(app: &EhallApp) -> Result<Self, EhallError> {
+        let groups = app
+            .emap(Self::GROUPS_PATH)
+            .order_by("KZH", SortOrder::Asc)
+            .fetch_all()?;
+        let courses = app
+            .emap(Self::COURSES_PATH)
+            .order_by("KZH", SortOrder::Asc)
+            .order_by("KCH", SortOrder::Asc)
+            .fetch_all()?;
+        Ok(Self::new(groups, courses))
+    }
+    #[inline]
+    pub fn courses_in<'a>(&'a self, group_id: &'a str) -> impl Iterator<Item = &'a PlanCourse> {
+        self.courses.iter().filter(move |c| c.group_id == group_id)
+    }
+    fn earned_credits(&self, group_id: &str, passed: &HashSet<&str>, depth: usize) -> f64 {
+        // 防止数据有误时出现环。
+        if depth > self.groups.len() {
+            return 0.0;
+        }
+        let own = self
+            .courses_in(group_id)
+            .filter(|c| passed.contains(c.course_code.as_str()))
+            .map(|c| c.credit)
+            .sum::<f64>();
+        let children = self
+            .groups
+            .iter()
+            .filter(|g| g.parent_id.as_deref() == Some(group_id))
+            .map(|g| self.earned_credits(&g.id, passed, depth + 1))
+            .sum::<f64>();
+        own + children
+    }
+    /// 对照已通过的课程（同一课程只要有一次通过即可）审核各课程组的学分。
+    pub fn audit(&self, grades: &[Grade]) -> CreditAudit {
+        let passed = grades
+            .iter()
+            .filter(|g| g.score().is_passing())
+            .map(|g| g.course_code().as_str())
+            .collect::<HashSet<_>>();
+        let groups = self
+            .groups
+            .iter()
+            .map(|g| GroupAudit {
+                group_id: g.id.clone(),
+                group_name: g.name.clone(),
+                parent_id: g.parent_id.clone(),
+                required_credits: g.min_credits,
+                earned_credits: self.earned_credits(&g.id, &passed, 0),
+                outstanding_mandatory: self
+                    .courses_in(&g.id)
+                    .filter(|c| c.is_mandatory && !passed.contains(c.course_code.as_str()))
+                    .cloned()
+                    .collect(),
+            })
+            .collect();
+        CreditAudit { groups }
+    }
+}
+
+impl EhallSession {
+    #[inline]
+    pub fn get_training_plan(&self) -> Result<TrainingPlan, EhallError> {
+        TrainingPlan::fetch(&self.open_app(TrainingPlan::APP_ID)?)
+    }
+    /// 获取培养方案及所有成绩，并进行学分审核。
+    #[inline]
+    pub fn audit_credits(&self) -> Result<CreditAudit, EhallError> {
+        Ok(self.get_training_plan()?.audit(&self.get_grades(None)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audit() {
+        let groups: Vec<CourseGroup> = serde_json::from_str(
+            r#"[{"KZH":"1","KZM":"通识教育","ZSXF":"6"},{"KZH":"2","KZM":"数学","FKZH":"1","ZSXF":4}]"#,
+        )
+        .unwrap();
+        let courses: Vec<PlanCourse> = serde_json::from_str(
+            r#"[{"KZH":"1","KCH":"PE","KCM":"体育","XF":1,"SFBX":"1"},{"KZH":"2","KCH":"MA1","KCM":"高数一","XF":"4","SFBX":"是"},{"KZH":"2","KCH":"MA2","KCM":"高数二","XF":4,"SFBX":"0"}]"#,
+        )
+        .unwrap();
+        let grades: Vec<Grade> = serde_json::from_str(
+            r#"[{"KCH":"MA1","ZCJ":"75","XF":4},{"KCH":"PE","ZCJ":"不及格","XF":1}]"#,
+        )
+        .unwrap();
+        let audit = TrainingPlan::new(groups, courses).audit(&grades);
+        let top = &audit.groups()[0];
+        assert_eq!(top.earned_credits(), 4.0);
+        assert_eq!(top.outstanding_mandatory()[0].course_code(), "PE");
+        assert!(audit.groups()[1].is_satisfied());
+        assert!(!audit.is_satisfied());
+        assert_eq!(audit.total_required(), 6.0);
+    }
+}
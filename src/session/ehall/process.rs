@@ -0,0 +1,285 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    EhallSession,
+    error::EhallError,
+    session::ehall::{PORTAL_PAGE_SIZE, fetch_portal_pages, parse_portal_data},
+    utils::de,
+};
+use chrono::NaiveDateTime;
+use getset2::Getset2;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ApplicationStatus {
+    /// 审批中。
+    Pending,
+    Approved,
+    Rejected,
+    /// 已撤回。
+    Withdrawn,
+    /// 其他状态，保留原文。
+    Other(String),
+}
+impl ApplicationStatus {
+    pub fn from_display(s: &str) -> Self {
+        match s.trim() {
+            "审批中" | "审核中" | "办理中" | "待审核" | "处理中" => {
+                ApplicationStatus::Pending
+            }
+            "已通过" | "审核通过" | "审批通过" | "已办结" | "完成" => {
+                ApplicationStatus::Approved
+            }
+            "未通过" | "审核不通过" | "已驳回" | "驳回" | "不同意" => {
+                ApplicationStatus::Rejected
+            }
+            "已撤回" | "撤回" => ApplicationStatus::Withdrawn,
+            s => ApplicationStatus::Other(s.to_owned()),
+        }
+    }
+}
+
+/// 审批记录。
+#[derive(Deserialize, Debug, Clone, Eq, PartialEq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct ApprovalRecord {
+    /// 环节名称。
+    #[serde(rename = "nodeName", deserialize_with = "de::string_lenient", default)]
+    node: String,
+    #[serde(rename = "assigneeName", deserialize_with = "de::opt_string", default)]
+    approver: Option<String>,
+    /// 处理结果，如“同意”。
+    #[serde(rename = "actionName", deserialize_with = "de::opt_string", default)]
+    action: Option<String>,
+    #[serde(deserialize_with = "de::opt_string", default)]
+    comment: Option<String>,
+    #[serde(
+        rename = "endTime",
+        deserialize_with = "de::opt_datetime_lenient",
+        default
+    )]
+    #[getset2(get_copy(pub), skip(get_ref))]
+    time: Option<NaiveDateTime>,
+}
+
+#[derive(Deserialize)]
+struct ApplicationRow {
+    #[serde(
+        rename = "instanceId",
+        deserialize_with = "de::string_lenient",
+        default
+    )]
+    instance_id: String,
+    #[serde(
+        rename = "processName",
+        deserialize_with = "de::string_lenient",
+        default
+    )]
+    process_name: String,
+    #[serde(
+        rename = "startTime",
+        deserialize_with = "de::opt_datetime_lenient",
+        default
+    )]
+    submit_time: Option<NaiveDateTime>,
+    #[serde(
+        rename = "currentNodeName",
+        deserialize_with = "de::opt_string",
+        default
+    )]
+    current_node: Option<String>,
+    #[serde(
+        rename = "statusName",
+        deserialize_with = "de::string_lenient",
+        default
+    )]
+    status: String,
+}
+
+/// 通过服务流程提交的申请。
+#[derive(Debug, Clone, Eq, PartialEq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct Application {
+    instance_id: String,
+    process_name: String,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    submit_time: Option<NaiveDateTime>,
+    /// 当前所处的环节，流程结束后为 `None`.
+    current_node: Option<String>,
+    status: ApplicationStatus,
+    /// 审批记录，尚未获取时为 `None`, 见 [`Application::load_history`].
+    history: Option<Vec<ApprovalRecord>>,
+}
+impl From<ApplicationRow> for Application {
+    fn from(row: ApplicationRow) -> Self {
+        Self {
+            instance_id: row.instance_id,
+            process_name: row.process_name,
+            submit_time: row.submit_time,
+            current_node: row.current_node,
+            status: ApplicationStatus::from_display(&row.status),
+            history: None,
+        }
+    }
+}
+impl Application {
+    #[inline]
+    pub fn is_pending(&self) -> bool {
+        self.status == ApplicationStatus::Pending
+    }
+    /// 获取审批记录，已获取过时不再请求。
+    pub fn load_history(
+        &mut self,
+        session: &EhallSession,
+    ) -> Result<&[ApprovalRecord], EhallError> {
+        if self.history.is_none() {
+            self.history = Some(session.get_approval_history(&self.instance_id)?);
+        }
+        Ok(self.history.as_deref().unwrap_or_default())
+    }
+    /// 最近一次处理（无审批记录或尚未获取时为提交）的时间。
+    #[inline]
+    pub fn last_activity(&self) -> Option<NaiveDateTime> {
+        self.history
+            .iter()
+            .flatten()
+            .filter_map(|r| r.time)
+            .max()
+            .or(self.submit_time)
+    }
+}
+
+/// 审批记录获取失败的申请的 `instance_id` 及错误。
+pub type HistoryFailures = Vec<(String, EhallError)>;
+
+/// 逐个获取审批记录，仅在登录失效时中止，其余错误记入返回值。
+fn load_histories(
+    applications: &mut [Application],
+    mut get_history: impl FnMut(&str) -> Result<Vec<ApprovalRecord>, EhallError>,
+) -> Result<HistoryFailures, EhallError> {
+    let mut failures = Vec::new();
+    for a in applications.iter_mut().filter(|a| a.history.is_none()) {
+        match get_history(&a.instance_id) {
+            Ok(history) => a.history = Some(history),
+            Err(EhallError::NotLoggedIn) => return Err(EhallError::NotLoggedIn),
+            Err(e) => failures.push((a.instance_id.clone(), e)),
+        }
+    }
+    Ok(failures)
+}
+
+impl EhallSession {
+    /// 当前用户提交的申请，不含审批记录。
+    pub fn get_applications(&self) -> Result<Vec<Application>, EhallError> {
+        Ok(fetch_portal_pages::<ApplicationRow>(|n| {
+            crate::protocol::ehall::get_my_applications(self, n, PORTAL_PAGE_SIZE)
+        })?
+        .into_iter()
+        .map(Application::from)
+        .collect())
+    }
+    pub fn get_approval_history(
+        &self,
+        instance_id: &str,
+    ) -> Result<Vec<ApprovalRecord>, EhallError> {
+        let text = crate::protocol::ehall::get_approval_history(self, instance_id)?
+            .into_body()
+            .read_to_string()?;
+        parse_portal_data(&text)
+    }
+    /// 当前用户提交的申请及其审批记录。
+    ///
+    /// 每个申请的审批记录需要单独请求一次，申请较多时开销较大，可改用
+    /// [`Self::get_applications`] 并按需调用 [`Application::load_history`].
+    /// 个别申请的审批记录获取失败时，该申请的记录保持为 `None`, 错误与其 `instance_id` 一并返回；
+    /// 仅在登录失效时返回错误。
+    pub fn get_applications_with_history(
+        &self,
+    ) -> Result<(Vec<Application>, HistoryFailures), EhallError> {
+        let mut applications = self.get_applications()?;
+        let failures = load_histories(&mut applications, |id| self.get_approval_history(id))?;
+        Ok((applications, failures))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_application() {
+        let rows: Vec<ApplicationRow> = parse_portal_data(
+            r#"{"hasLogin":true,"data":[{"instanceId":"9527","processName":"学生请假","startTime":"2024-10-01 08:00:00","currentNodeName":"辅导员审核","statusName":"审批中"}]}"#,
+        )
+        .unwrap();
+        let mut application = Application::from(rows.into_iter().next().unwrap());
+        assert!(application.is_pending());
+        assert_eq!(
+            application
+                .last_activity()
+                .map(|t| t.to_string())
+                .as_deref(),
+            Some("2024-10-01 08:00:00")
+        );
+        application.history = Some(parse_portal_data(
+            r#"{"hasLogin":true,"data":[{"nodeName":"提交","assigneeName":"张三","actionName":"提交","endTime":"2024-10-01 08:00:00"},{"nodeName":"班主任审核","actionName":"同意","endTime":"2024-10-02 09:30:00"}]}"#,
+        )
+        .unwrap());
+        assert_eq!(
+            application
+                .last_activity()
+                .map(|t| t.to_string())
+                .as_deref(),
+            Some("2024-10-02 09:30:00")
+        );
+        assert_eq!(
+            ApplicationStatus::from_display("暂存"),
+            ApplicationStatus::Other("暂存".to_owned())
+        );
+    }
+    #[test]
+    fn test_load_histories() {
+        let rows: Vec<ApplicationRow> = parse_portal_data(
+            r#"{"hasLogin":true,"data":[{"instanceId":"1","processName":"学生请假"},{"instanceId":"2","processName":"学生请假"},{"instanceId":"3","processName":"学生请假"}]}"#,
+        )
+        .unwrap();
+        let mut applications = rows.into_iter().map(Application::from).collect::<Vec<_>>();
+        let failures = load_histories(&mut applications, |id| match id {
+            "2" => Err(EhallError::ParseError("bad json".to_owned())),
+            _ => Ok(Vec::new()),
+        })
+        .unwrap();
+        assert_eq!(applications.len(), 3);
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0, "2");
+        assert!(matches!(failures[0].1, EhallError::ParseError(_)));
+        assert!(applications[1].history.is_none());
+        assert!(applications[0].history.is_some() && applications[2].history.is_some());
+        // 登录失效时中止。
+        applications[1].history = None;
+        assert!(matches!(
+            load_histories(&mut applications, |_| Err(EhallError::NotLoggedIn)),
+            Err(EhallError::NotLoggedIn)
+        ));
+    }
+}
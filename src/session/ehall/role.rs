@@ -0,0 +1,93 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{EhallSession, error::EhallError, session::ehall::parse_portal_data, utils::de};
+use getset2::Getset2;
+use serde::Deserialize;
+
+/// ehall 中的身份，如“学生”“教职工”。
+#[derive(Deserialize, Debug, Clone, Eq, PartialEq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct Role {
+    #[serde(rename = "roleId", deserialize_with = "de::string_lenient", default)]
+    id: String,
+    #[serde(rename = "roleName", deserialize_with = "de::string_lenient", default)]
+    name: String,
+    /// 是否为当前身份。
+    #[serde(rename = "isCurrent", deserialize_with = "de::bool_lenient", default)]
+    #[getset2(get_copy(pub), skip(get_ref))]
+    is_current: bool,
+}
+
+impl EhallSession {
+    /// 当前用户可用的身份。
+    pub fn get_roles(&self) -> Result<Vec<Role>, EhallError> {
+        let text = crate::protocol::ehall::get_user_roles(self)?
+            .into_body()
+            .read_to_string()?;
+        parse_portal_data(&text)
+    }
+    /// 当前身份，只有一个身份时服务器可能不标记当前身份，此时返回该身份。
+    pub fn current_role(&self) -> Result<Option<Role>, EhallError> {
+        let roles = self.get_roles()?;
+        if roles.len() == 1 {
+            return Ok(roles.into_iter().next());
+        }
+        Ok(roles.into_iter().find(|r| r.is_current))
+    }
+    /// 切换身份，`role` 为身份的 id 或名称。
+    ///
+    /// 切换前打开的 [`EhallApp`](crate::EhallApp) 仍处于原身份的作用域，需调用其 `reenter` 或重新打开。
+    pub fn switch_role(&self, role: &str) -> Result<Role, EhallError> {
+        let target = self
+            .get_roles()?
+            .into_iter()
+            .find(|r| r.id == role || r.name == role)
+            .ok_or_else(|| EhallError::ServerError(format!("没有身份 `{role}`.")))?;
+        if target.is_current {
+            return Ok(target);
+        }
+        let text = crate::protocol::ehall::select_role(self, &target.id)?
+            .into_body()
+            .read_to_string()?;
+        parse_portal_data::<serde_json::Value>(&text)?;
+        Ok(Role {
+            is_current: true,
+            ..target
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roles() {
+        let roles: Vec<Role> = parse_portal_data(
+            r#"{"hasLogin":true,"data":[{"roleId":"1","roleName":"教职工","isCurrent":true},{"roleId":"2","roleName":"研究生","isCurrent":false}]}"#,
+        )
+        .unwrap();
+        assert!(roles[0].is_current());
+        assert_eq!(roles[1].name(), "研究生");
+    }
+}
@@ -0,0 +1,101 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{EhallApp, EhallSession, error::EhallError, utils::de};
+use chrono::NaiveDate;
+use getset2::Getset2;
+use serde::Deserialize;
+
+/// 学籍信息。
+#[derive(Deserialize, Debug, Clone, PartialEq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct StudentRecord {
+    /// 学号。
+    #[serde(rename = "XH", deserialize_with = "de::string_lenient", default)]
+    student_id: String,
+    #[serde(rename = "XM", deserialize_with = "de::string_lenient", default)]
+    name: String,
+    /// 学院。
+    #[serde(rename = "YXDM_DISPLAY", deserialize_with = "de::opt_string", default)]
+    college: Option<String>,
+    #[serde(rename = "ZYDM_DISPLAY", deserialize_with = "de::opt_string", default)]
+    major: Option<String>,
+    #[serde(rename = "BJDM_DISPLAY", deserialize_with = "de::opt_string", default)]
+    class: Option<String>,
+    /// 年级，如 `2021`.
+    #[serde(rename = "XZNJ", deserialize_with = "de::opt_string", default)]
+    grade: Option<String>,
+    /// 入学日期，仅有年月时取当月 1 日。
+    #[serde(rename = "RXNY", deserialize_with = "de::opt_date_lenient", default)]
+    #[getset2(get_copy(pub), skip(get_ref))]
+    enrollment_date: Option<NaiveDate>,
+    /// 学制（年）。
+    #[serde(rename = "XZ", deserialize_with = "de::opt_f64_lenient", default)]
+    #[getset2(get_copy(pub), skip(get_ref))]
+    program_length: Option<f64>,
+    /// 学籍状态，如“在读”。
+    #[serde(
+        rename = "XJZTDM_DISPLAY",
+        deserialize_with = "de::opt_string",
+        default
+    )]
+    status: Option<String>,
+}
+impl StudentRecord {
+    /// 学生基本信息。
+    pub const APP_ID: &'static str = "4585275700341858";
+    pub const RECORD_PATH: &'static str = "modules/xsjbxx/xsjbxxcx.do";
+
+    pub fn fetch(app: &EhallApp) -> Result<Self, EhallError> {
+        app.emap(Self::RECORD_PATH)
+            .fetch_all()?
+            .into_iter()
+            .next()
+            .ok_or_else(|| EhallError::ServerError("未找到学籍信息。".to_owned()))
+    }
+}
+impl EhallSession {
+    #[inline]
+    pub fn get_student_record(&self) -> Result<StudentRecord, EhallError> {
+        StudentRecord::fetch(&self.open_app(StudentRecord::APP_ID)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_student_record() {
+        let record: StudentRecord = serde_json::from_str(
+            r#"{"XH":"21009200001","XM":"张三","YXDM_DISPLAY":"计算机科学与技术学院","ZYDM_DISPLAY":"软件工程","BJDM_DISPLAY":"2103011","XZNJ":"2021","RXNY":"2021-09","XZ":"4","XJZTDM_DISPLAY":"在读"}"#,
+        )
+        .unwrap();
+        assert_eq!(record.student_id(), "21009200001");
+        assert_eq!(
+            record.enrollment_date(),
+            NaiveDate::from_ymd_opt(2021, 9, 1)
+        );
+        assert_eq!(record.program_length(), Some(4.0));
+        assert_eq!(record.status().as_deref(), Some("在读"));
+    }
+}
@@ -0,0 +1,374 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    EhallApp, EhallSession, EmapBuilder, SortOrder,
+    error::EhallError,
+    utils::{IcsCalendar, IcsEvent, de},
+};
+use chrono::{Days, NaiveDate, NaiveTime};
+use getset2::Getset2;
+use serde::{Deserialize, Serialize};
+
+/// 学年学期，如 `2024-2025-1`.
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct Semester {
+    #[serde(rename = "DM", alias = "XNXQDM")]
+    code: String,
+    #[serde(rename = "MC", alias = "XNXQDM_DISPLAY", default)]
+    name: String,
+}
+
+#[derive(Deserialize, Serialize)]
+struct CourseRow {
+    #[serde(rename = "KCM", deserialize_with = "de::string_lenient", default)]
+    name: String,
+    #[serde(rename = "KCH", deserialize_with = "de::string_lenient", default)]
+    course_code: String,
+    #[serde(rename = "KXH", deserialize_with = "de::string_lenient", default)]
+    class_code: String,
+    #[serde(rename = "SKJS", deserialize_with = "de::opt_string", default)]
+    teacher: Option<String>,
+    #[serde(rename = "JASMC", deserialize_with = "de::opt_string", default)]
+    location: Option<String>,
+    #[serde(rename = "SKXQ", deserialize_with = "de::u32_lenient", default)]
+    weekday: u32,
+    #[serde(rename = "KSJC", deserialize_with = "de::u32_lenient", default)]
+    start_section: u32,
+    #[serde(rename = "JSJC", deserialize_with = "de::u32_lenient", default)]
+    end_section: u32,
+    #[serde(rename = "SKZC", deserialize_with = "de::string_lenient", default)]
+    week_bitmap: String,
+}
+
+/// 一门课程在一周内的一次安排。
+/// 序列化为与接口相同的字段，因此导出的数据可以再被读取。
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Getset2)]
+#[serde(from = "CourseRow", into = "CourseRow")]
+#[getset2(get_ref(pub))]
+pub struct Course {
+    name: String,
+    course_code: String,
+    class_code: String,
+    teacher: Option<String>,
+    location: Option<String>,
+    /// 星期几，1 为周一，7 为周日。
+    #[getset2(get_copy(pub), skip(get_ref))]
+    weekday: u32,
+    /// 开始节次，从 1 开始。
+    #[getset2(get_copy(pub), skip(get_ref))]
+    start_section: u32,
+    /// 结束节次（含）。
+    #[getset2(get_copy(pub), skip(get_ref))]
+    end_section: u32,
+    /// 上课的周次，从 1 开始。
+    weeks: Vec<u32>,
+}
+impl Course {
+    #[inline]
+    pub fn sections(&self) -> std::ops::RangeInclusive<u32> {
+        self.start_section..=self.end_section
+    }
+    #[inline]
+    pub fn has_week(&self, week: u32) -> bool {
+        self.weeks.contains(&week)
+    }
+}
+impl From<CourseRow> for Course {
+    fn from(row: CourseRow) -> Self {
+        Self {
+            weeks: decode_week_bitmap(&row.week_bitmap),
+            name: row.name,
+            course_code: row.course_code,
+            class_code: row.class_code,
+            teacher: row.teacher,
+            location: row.location,
+            weekday: row.weekday,
+            start_section: row.start_section,
+            end_section: row.end_section,
+        }
+    }
+}
+
+impl From<Course> for CourseRow {
+    fn from(course: Course) -> Self {
+        Self {
+            week_bitmap: encode_week_bitmap(&course.weeks),
+            name: course.name,
+            course_code: course.course_code,
+            class_code: course.class_code,
+            teacher: course.teacher,
+            location: course.location,
+            weekday: course.weekday,
+            start_section: course.start_section,
+            end_section: course.end_section,
+        }
+    }
+}
+
+/// `SKZC` 字段为逐周的 `0`/`1` 串，第一个字符对应第 1 周。
+pub fn decode_week_bitmap(bitmap: &str) -> Vec<u32> {
+    bitmap
+        .chars()
+        .enumerate()
+        .filter(|(_, c)| *c == '1')
+        .map(|(i, _)| i as u32 + 1)
+        .collect()
+}
+
+/// [`decode_week_bitmap`] 的逆运算。
+pub fn encode_week_bitmap(weeks: &[u32]) -> String {
+    let len = weeks.iter().copied().max().unwrap_or(0);
+    (1..=len)
+        .map(|w| if weeks.contains(&w) { '1' } else { '0' })
+        .collect()
+}
+
+/// 节次与上下课时间的对应关系。
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SectionTimes(Vec<(NaiveTime, NaiveTime)>);
+impl SectionTimes {
+    /// 第 `i` 项为第 `i + 1` 节的上下课时间。
+    #[inline]
+    pub fn new(times: Vec<(NaiveTime, NaiveTime)>) -> Self {
+        Self(times)
+    }
+    pub fn xidian() -> Self {
+        const TIMES: [((u32, u32), (u32, u32)); 11] = [
+            ((8, 30), (9, 15)),
+            ((9, 20), (10, 5)),
+            ((10, 25), (11, 10)),
+            ((11, 15), (12, 0)),
+            ((14, 0), (14, 45)),
+            ((14, 50), (15, 35)),
+            ((15, 55), (16, 40)),
+            ((16, 45), (17, 30)),
+            ((19, 0), (19, 45)),
+            ((19, 55), (20, 40)),
+            ((20, 45), (21, 30)),
+        ];
+        let t = |(h, m)| NaiveTime::from_hms_opt(h, m, 0).expect("invalid section time.");
+        Self(TIMES.into_iter().map(|(s, e)| (t(s), t(e))).collect())
+    }
+    #[inline]
+    pub fn get(&self, section: u32) -> Option<(NaiveTime, NaiveTime)> {
+        self.0.get(section.checked_sub(1)? as usize).copied()
+    }
+    /// 从 `start` 节上课到 `end` 节下课。
+    #[inline]
+    pub fn span(&self, start: u32, end: u32) -> Option<(NaiveTime, NaiveTime)> {
+        Some((self.get(start)?.0, self.get(end)?.1))
+    }
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+impl Default for SectionTimes {
+    #[inline]
+    fn default() -> Self {
+        Self::xidian()
+    }
+}
+
+/// 第 `week` 周星期 `weekday` 的日期，`semester_start` 为第 1 周的周一。
+#[inline]
+pub fn date_of_week(semester_start: NaiveDate, week: u32, weekday: u32) -> Option<NaiveDate> {
+    let days = (week.checked_sub(1)? * 7 + weekday.checked_sub(1)?) as u64;
+    semester_start.checked_add_days(Days::new(days))
+}
+
+/// 可序列化，便于导出后与他人的课表一同计算共同空闲时段。
+#[derive(Deserialize, Serialize, Debug, Clone, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct Timetable {
+    semester: String,
+    courses: Vec<Course>,
+}
+impl Timetable {
+    /// 我的本科课表。
+    pub const APP_ID: &'static str = "4770397878132218";
+    pub const SEMESTERS_PATH: &'static str = "modules/jshkcb/xnxqcx.do";
+    pub const CURRENT_SEMESTER_PATH: &'static str = "modules/jshkcb/dqxnxq.do";
+    pub const COURSES_PATH: &'static str = "modules/xskcb/xskcb.do";
+
+    #[inline]
+    pub fn new(semester: String, courses: Vec<Course>) -> Self {
+        Self { semester, courses }
+    }
+    /// 某一周、某一天的课程，按节次排序。
+    pub fn courses_on(&self, week: u32, weekday: u32) -> Vec<&Course> {
+        let mut courses = self
+            .courses
+            .iter()
+            .filter(|c| c.weekday == weekday && c.has_week(week))
+            .collect::<Vec<_>>();
+        courses.sort_by_key(|c| c.start_section);
+        courses
+    }
+    #[inline]
+    pub fn max_week(&self) -> u32 {
+        self.courses
+            .iter()
+            .filter_map(|c| c.weeks.last().copied())
+            .max()
+            .unwrap_or(0)
+    }
+    /// 每门课每次上课对应一个事件，节次不在 `section_times` 中的课程将被跳过。
+    pub fn to_ics_events(
+        &self,
+        semester_start: NaiveDate,
+        section_times: &SectionTimes,
+    ) -> Vec<IcsEvent> {
+        let mut events = Vec::new();
+        for c in &self.courses {
+            let Some((start, end)) = section_times.span(c.start_section, c.end_section) else {
+                continue;
+            };
+            for &week in &c.weeks {
+                let Some(date) = date_of_week(semester_start, week, c.weekday) else {
+                    continue;
+                };
+                let description = [
+                    c.teacher.as_deref().map(|t| format!("教师：{t}")),
+                    Some(format!("课程号：{}-{}", c.course_code, c.class_code)),
+                    Some(format!(
+                        "第 {week} 周，第 {}-{} 节",
+                        c.start_section, c.end_section
+                    )),
+                ];
+                events.push(IcsEvent {
+                    uid: format!(
+                        "{}-{}-{}-{week}-{}-{}@x_l4rs",
+                        self.semester, c.course_code, c.class_code, c.weekday, c.start_section
+                    ),
+                    summary: c.name.clone(),
+                    location: c.location.clone(),
+                    description: Some(
+                        description
+                            .into_iter()
+                            .flatten()
+                            .collect::<Vec<_>>()
+                            .join("\n"),
+                    ),
+                    start: date.and_time(start),
+                    end: date.and_time(end),
+                });
+            }
+        }
+        events
+    }
+    #[inline]
+    pub fn to_ics(&self, semester_start: NaiveDate, section_times: &SectionTimes) -> String {
+        let mut calendar = IcsCalendar::new(&format!("课表 {}", self.semester));
+        calendar.extend(self.to_ics_events(semester_start, section_times));
+        calendar.to_string()
+    }
+    pub fn semesters(app: &EhallApp) -> Result<Vec<Semester>, EhallError> {
+        app.emap(Self::SEMESTERS_PATH)
+            .order_by("DM", SortOrder::Desc)
+            .fetch_all()
+    }
+    pub fn current_semester(app: &EhallApp) -> Result<Semester, EhallError> {
+        app.emap(Self::CURRENT_SEMESTER_PATH)
+            .fetch_all()?
+            .into_iter()
+            .next()
+            .ok_or_else(|| EhallError::ServerError("未找到当前学期。".to_owned()))
+    }
+    pub fn fetch(app: &EhallApp, semester: &str) -> Result<Self, EhallError> {
+        let courses = app
+            .emap(Self::COURSES_PATH)
+            .filter("XNXQDM", EmapBuilder::Equal, semester)
+            .param("XNXQDM", semester)
+            .fetch_all()?;
+        Ok(Self::new(semester.to_owned(), courses))
+    }
+}
+
+impl EhallSession {
+    #[inline]
+    pub fn open_timetable_app(&self) -> Result<EhallApp<'_>, EhallError> {
+        self.open_app(Timetable::APP_ID)
+    }
+    /// 可查询课表的学期，新学期在前。
+    #[inline]
+    pub fn get_semesters(&self) -> Result<Vec<Semester>, EhallError> {
+        Timetable::semesters(&self.open_timetable_app()?)
+    }
+    #[inline]
+    pub fn get_current_semester(&self) -> Result<Semester, EhallError> {
+        Timetable::current_semester(&self.open_timetable_app()?)
+    }
+    #[inline]
+    pub fn get_timetable(&self, semester: &str) -> Result<Timetable, EhallError> {
+        Timetable::fetch(&self.open_timetable_app()?, semester)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_week_bitmap() {
+        assert_eq!(decode_week_bitmap("0111000001"), vec![2, 3, 4, 10]);
+        assert!(decode_week_bitmap("").is_empty());
+        assert_eq!(encode_week_bitmap(&[2, 3, 4, 10]), "0111000001");
+        assert_eq!(encode_week_bitmap(&[]), "");
+    }
+    #[test]
+    fn test_course_row() {
+        let row: CourseRow = serde_json::from_str(
+            r#"{"KCM":"高等数学","KCH":"MA101","KXH":"01","SKJS":"张三","JASMC":"B-101","SKXQ":"3","KSJC":1,"JSJC":"2","SKZC":"1111"}"#,
+        )
+        .unwrap();
+        let course = Course::from(row);
+        assert_eq!(course.weekday(), 3);
+        assert_eq!(course.sections(), 1..=2);
+        assert_eq!(course.weeks(), &vec![1, 2, 3, 4]);
+        let timetable = Timetable::new("2024-2025-1".to_owned(), vec![course]);
+        assert_eq!(timetable.courses_on(2, 3).len(), 1);
+        assert!(timetable.courses_on(5, 3).is_empty());
+        assert_eq!(timetable.max_week(), 4);
+        let start = NaiveDate::from_ymd_opt(2024, 9, 2).unwrap();
+        let events = timetable.to_ics_events(start, &SectionTimes::xidian());
+        assert_eq!(events.len(), 4);
+        assert_eq!(
+            events[1].start,
+            NaiveDate::from_ymd_opt(2024, 9, 11)
+                .unwrap()
+                .and_hms_opt(8, 30, 0)
+                .unwrap()
+        );
+        assert_eq!(events[1].uid, "2024-2025-1-MA101-01-2-3-1@x_l4rs");
+        let exported = serde_json::to_string(&timetable).unwrap();
+        assert!(exported.contains(r#""SKZC":"1111""#));
+        let imported: Timetable = serde_json::from_str(&exported).unwrap();
+        assert_eq!(imported.courses(), timetable.courses());
+    }
+}
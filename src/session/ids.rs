@@ -23,7 +23,7 @@
 use crate::{
     LOGIN_RETRY_TIMES, XL4rsSessionTrait,
     error::{CaptchaError, LoginError},
-    protocol::ids as ids_protocol,
+    protocol::ids::{self as ids_protocol, CasProfile},
     utils::{
         X_L4RS_ENC_IV, aes_enc, base64_dec, base64_enc, find_form_content, find_id_value_pair,
         get_now_timestamp_mills, image_from_bytes, pkcs7_pad,
@@ -37,6 +37,7 @@ use ureq::Agent;
 
 fn solve_captcha(
     agent: &Agent,
+    profile: &CasProfile,
     captcha_solver: &impl Fn(&DynamicImage, &DynamicImage) -> Result<u32, CaptchaError>,
 ) -> Result<u32, CaptchaError> {
     #[derive(Deserialize)]
@@ -49,7 +50,7 @@ fn solve_captcha(
     let Images {
         small_image,
         big_image,
-    } = ids_protocol::open_slider_captcha(agent, get_now_timestamp_mills())?
+    } = ids_protocol::open_slider_captcha(agent, profile, get_now_timestamp_mills())?
         .into_body()
         .read_json()
         .expect("Failed to parse captcha slider");
@@ -58,28 +59,44 @@ fn solve_captcha(
     let big_image = image_from_bytes(big_image);
     let small_image = image_from_bytes(small_image);
     let v = captcha_solver(&big_image, &small_image)?;
-    let r = v * 280 / big_image.width();
+    let r = v * profile.canvas_width / big_image.width();
     debug!("{v}, {r}");
     Ok(r)
 }
 #[derive(Eq, PartialEq)]
 pub struct IDSLoginImpl {
     target: &'static str,
+    profile: CasProfile,
 }
 impl IDSLoginImpl {
     #[inline]
     pub fn new(target: &'static str) -> IDSLoginImpl {
-        IDSLoginImpl { target }
+        IDSLoginImpl {
+            target,
+            profile: CasProfile::XIDIAN,
+        }
+    }
+    /// 使用其他学校部署的 authserver 登录。
+    #[inline]
+    pub fn with_profile(mut self, profile: CasProfile) -> IDSLoginImpl {
+        self.profile = profile;
+        self
     }
     #[inline]
     pub fn target(&self) -> &'static str {
         self.target
     }
+    #[inline]
+    pub fn profile(&self) -> &CasProfile {
+        &self.profile
+    }
     pub const TARGET_LEARNING: Self = Self {
         target: "https://learning.xidian.edu.cn/cassso/xidian",
+        profile: CasProfile::XIDIAN,
     };
     pub const TARGET_EHALL: Self = Self {
         target: "http://ehall.xidian.edu.cn/login?service=http://ehall.xidian.edu.cn/new/index.html",
+        profile: CasProfile::XIDIAN,
     };
     pub fn login(
         &self,
@@ -88,12 +105,18 @@ impl IDSLoginImpl {
         passwd: &[u8],
         captcha_solver: &impl Fn(&DynamicImage, &DynamicImage) -> Result<u32, CaptchaError>,
     ) -> Result<(), LoginError> {
-        let page = ids_protocol::login_page(agent, self.target)?
+        let profile = &self.profile;
+        let page = ids_protocol::login_page(agent, profile, self.target)?
             .into_body()
             .read_to_string()
             .expect("登录页获取失败。");
         for i in 0..=LOGIN_RETRY_TIMES {
-            let r = ids_protocol::check_need_captcha(agent, account, get_now_timestamp_mills());
+            let r = ids_protocol::check_need_captcha(
+                agent,
+                profile,
+                account,
+                get_now_timestamp_mills(),
+            );
             let r = r
                 .map_err(LoginError::from)
                 .and_then(|r| {
@@ -105,11 +128,12 @@ impl IDSLoginImpl {
                             #[serde(rename = "errorMsg")]
                             error_msg: String,
                         }
-                        let v = solve_captcha(agent, captcha_solver)?;
-                        let Tmp { error_msg } = ids_protocol::verify_slider_captcha(agent, v)?
-                            .into_body()
-                            .read_json()
-                            .expect("json parse failed.");
+                        let v = solve_captcha(agent, profile, captcha_solver)?;
+                        let Tmp { error_msg } =
+                            ids_protocol::verify_slider_captcha(agent, profile, v)?
+                                .into_body()
+                                .read_json()
+                                .expect("json parse failed.");
                         debug!("{error_msg}");
                         if error_msg == "success" {
                             Ok(())
@@ -135,8 +159,8 @@ impl IDSLoginImpl {
                 }
             }
         }
-        let inputs =
-            find_form_content(&["id=\"pwdLoginDiv\"", "id=\"pwdFromId\""], &page)?.split("<input ");
+        let [div_ident, form_ident] = profile.form_idents();
+        let inputs = find_form_content(&[&div_ident, &form_ident], &page)?.split("<input ");
         let mut key = None;
         let mut post_data = inputs
             .into_iter()
@@ -186,13 +210,14 @@ impl IDSLoginImpl {
         post_data.push(("password", &password));
         post_data.push(("remember_me", "true"));
         post_data.push(("captcha", ""));
-        let _ = ids_protocol::login(agent, self.target, post_data)?;
+        let _ = ids_protocol::login(agent, profile, self.target, post_data)?;
         Ok(())
     }
 }
 
 pub struct IDSSession {
     agent: Agent,
+    profile: CasProfile,
 }
 
 impl Deref for IDSSession {
@@ -214,7 +239,10 @@ impl IDSSession {
     ) -> Result<Self, LoginError> {
         let agent = crate::utils::build_agent_with_user_agent(ua);
         login_impl.login(&agent, account, passwd, captcha_solver)?;
-        Ok(IDSSession { agent })
+        Ok(IDSSession {
+            agent,
+            profile: login_impl.profile.clone(),
+        })
     }
     #[inline]
     pub fn login(
@@ -225,12 +253,19 @@ impl IDSSession {
     ) -> Result<Self, LoginError> {
         let agent = crate::utils::build_agent();
         login_impl.login(&agent, account, passwd, captcha_solver)?;
-        Ok(IDSSession { agent })
+        Ok(IDSSession {
+            agent,
+            profile: login_impl.profile.clone(),
+        })
+    }
+    #[inline]
+    pub fn profile(&self) -> &CasProfile {
+        &self.profile
     }
 }
 impl XL4rsSessionTrait for IDSSession {
     #[inline]
     fn has_logged_in(&self) -> bool {
-        crate::protocol::ids::has_logged_in(&self.agent)
+        crate::protocol::ids::has_logged_in(&self.agent, &self.profile)
     }
 }
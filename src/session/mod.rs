@@ -30,6 +30,10 @@ pub use ehall::*;
 mod ids;
 #[cfg(feature = "ids")]
 pub use ids::*;
+#[cfg(feature = "payment")]
+mod payment;
+#[cfg(feature = "payment")]
+pub use payment::*;
 #[cfg(feature = "rsbbs")]
 mod rsbbs;
 #[cfg(feature = "rsbbs")]
@@ -0,0 +1,202 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    IDSSession, XL4rsSessionTrait, error::PaymentError, protocol::ids::CasProfile, utils::de,
+};
+use getset2::Getset2;
+use serde::Deserialize;
+use std::ops::Deref;
+use ureq::{Agent, ResponseExt};
+
+/// 收费项目。
+#[derive(Deserialize, Debug, Clone, PartialEq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct FeeItem {
+    /// 学年，如 `2024-2025`.
+    #[serde(rename = "xn", default)]
+    year: String,
+    /// 收费类型，如“学费”“住宿费”。
+    #[serde(rename = "sfxmmc", default)]
+    fee_type: String,
+    /// 应缴金额，单位为分。
+    #[serde(rename = "ysje", deserialize_with = "de::amount_fen")]
+    #[getset2(get_copy(pub), skip(get_ref))]
+    amount_due: i64,
+    /// 已缴金额，单位为分。
+    #[serde(rename = "sfje", deserialize_with = "de::amount_fen")]
+    #[getset2(get_copy(pub), skip(get_ref))]
+    amount_paid: i64,
+    /// 欠费金额，单位为分。
+    #[serde(rename = "qfje", deserialize_with = "de::amount_fen")]
+    #[getset2(get_copy(pub), skip(get_ref))]
+    outstanding: i64,
+}
+impl FeeItem {
+    #[inline]
+    pub fn is_settled(&self) -> bool {
+        self.outstanding <= 0
+    }
+}
+
+/// 一个学年的收费项目及合计。
+#[derive(Debug, Clone, PartialEq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct FeeYear {
+    year: String,
+    items: Vec<FeeItem>,
+}
+impl FeeYear {
+    #[inline]
+    pub fn total_due(&self) -> i64 {
+        self.items.iter().map(|i| i.amount_due).sum()
+    }
+    #[inline]
+    pub fn total_paid(&self) -> i64 {
+        self.items.iter().map(|i| i.amount_paid).sum()
+    }
+    #[inline]
+    pub fn total_outstanding(&self) -> i64 {
+        self.items.iter().map(|i| i.outstanding).sum()
+    }
+    /// 按学年分组，保持原有顺序。
+    pub fn group(items: Vec<FeeItem>) -> Vec<FeeYear> {
+        let mut years: Vec<FeeYear> = Vec::new();
+        for item in items {
+            match years.iter_mut().find(|y| y.year == item.year) {
+                Some(y) => y.items.push(item),
+                None => years.push(FeeYear {
+                    year: item.year.clone(),
+                    items: vec![item],
+                }),
+            }
+        }
+        years
+    }
+}
+
+fn parse_fee_items(text: &str) -> Result<Vec<FeeItem>, PaymentError> {
+    #[derive(Deserialize)]
+    struct TmpData {
+        #[serde(default, deserialize_with = "de::opt_string")]
+        code: Option<String>,
+        #[serde(default)]
+        msg: Option<String>,
+        #[serde(default)]
+        data: Vec<FeeItem>,
+    }
+    let TmpData { code, msg, data } = serde_json::from_str(text).map_err(|e| {
+        if text.trim_start().starts_with('<') {
+            PaymentError::NotLoggedIn
+        } else {
+            PaymentError::ParseError(e.to_string())
+        }
+    })?;
+    match code {
+        None => Ok(data),
+        Some(code) if code == "0" => Ok(data),
+        Some(code) => Err(PaymentError::ServerError(msg.unwrap_or(code))),
+    }
+}
+
+/// 缴费平台，只读。
+///
+/// 通过统一身份认证的单点登录进入，需要已登录的 [`IDSSession`] 或
+/// [`EhallSession`](crate::EhallSession)；后者不记录登录所用的统一身份认证，需使用
+/// [`PaymentSession::sso_with_profile`].
+pub struct PaymentSession {
+    agent: Agent,
+}
+impl Deref for PaymentSession {
+    type Target = Agent;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.agent
+    }
+}
+impl PaymentSession {
+    /// 通过 `session` 登录时所用的统一身份认证进入，与 `session` 共享 Cookie.
+    #[inline]
+    pub fn sso(session: &IDSSession) -> Result<Self, PaymentError> {
+        Self::sso_with_profile(session, session.profile())
+    }
+    /// 与 `session` 共享 Cookie, `profile` 须与 `session` 登录时所用的统一身份认证一致。
+    pub fn sso_with_profile(
+        session: &impl XL4rsSessionTrait,
+        profile: &CasProfile,
+    ) -> Result<Self, PaymentError> {
+        let agent = session.deref().clone();
+        let r = crate::protocol::payment::sso_login(&agent, profile)?;
+        if r.get_uri().to_string().contains("/authserver/") {
+            return Err(PaymentError::NotLoggedIn);
+        }
+        Ok(Self { agent })
+    }
+    /// `year` 为 `None` 时查询所有学年。
+    pub fn get_fee_items(&self, year: Option<&str>) -> Result<Vec<FeeItem>, PaymentError> {
+        let text = crate::protocol::payment::get_fee_items(self, year.unwrap_or_default())?
+            .into_body()
+            .read_to_string()?;
+        parse_fee_items(&text)
+    }
+    #[inline]
+    pub fn get_fees(&self) -> Result<Vec<FeeYear>, PaymentError> {
+        Ok(FeeYear::group(self.get_fee_items(None)?))
+    }
+}
+impl XL4rsSessionTrait for PaymentSession {
+    #[inline]
+    fn has_logged_in(&self) -> bool {
+        crate::protocol::payment::has_logged_in(&self.agent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fees() {
+        let items = parse_fee_items(
+            r#"{"code":0,"data":[{"xn":"2024-2025","sfxmmc":"学费","ysje":"5000","sfje":5000,"qfje":0},{"xn":"2024-2025","sfxmmc":"住宿费","ysje":"1200.00","sfje":"0","qfje":"1200.00"},{"xn":"2023-2024","sfxmmc":"学费","ysje":5000,"sfje":5000,"qfje":0}]}"#,
+        )
+        .unwrap();
+        let years = FeeYear::group(items);
+        assert_eq!(years.len(), 2);
+        assert_eq!(years[0].total_due(), 620000);
+        assert_eq!(years[0].total_outstanding(), 120000);
+        assert!(!years[0].items()[1].is_settled());
+        assert!(years[1].items()[0].is_settled());
+        assert!(matches!(
+            parse_fee_items(r#"{"code":"1","msg":"未登录"}"#),
+            Err(PaymentError::ServerError(_))
+        ));
+        // 金额无法解析时应报错，而不是当作已结清。
+        assert!(matches!(
+            parse_fee_items(
+                r#"{"code":0,"data":[{"xn":"2024-2025","sfxmmc":"学费","ysje":"5000","sfje":"0","qfje":"待核定"}]}"#
+            ),
+            Err(PaymentError::ParseError(_))
+        ));
+    }
+}
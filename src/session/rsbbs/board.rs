@@ -0,0 +1,374 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    RSBBSSession,
+    error::RSBBSError,
+    session::rsbbs::{LastPost, check_discuz_page, first_anchor},
+    utils::rsbbs::{
+        find_attr, find_between, find_discuz_time, find_page_count, find_query_u64, first_u64,
+        html_to_text, leading_u64,
+    },
+};
+use chrono::NaiveDateTime;
+use getset2::Getset2;
+
+/// 主题分类。
+#[derive(Debug, Clone, PartialEq, Eq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct ThreadType {
+    #[getset2(get_copy(pub), skip(get_ref))]
+    type_id: u64,
+    name: String,
+    /// 该分类下的主题数，页面未显示时为 `None`.
+    #[getset2(get_copy(pub), skip(get_ref))]
+    threads: Option<u64>,
+}
+
+/// 主题列表中的一项。
+#[derive(Debug, Clone, PartialEq, Eq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct ThreadSummary {
+    #[getset2(get_copy(pub), skip(get_ref))]
+    tid: u64,
+    subject: String,
+    /// 主题分类名称。
+    type_name: Option<String>,
+    /// 匿名主题的作者为 `匿名`, 且没有 uid.
+    author: String,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    author_uid: Option<u64>,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    created: Option<NaiveDateTime>,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    replies: u64,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    views: u64,
+    last_reply: Option<LastPost>,
+    /// 置顶。
+    #[getset2(get_copy(pub), skip(get_ref))]
+    sticky: bool,
+    /// 精华。
+    #[getset2(get_copy(pub), skip(get_ref))]
+    digest: bool,
+    /// 已关闭，不能回复。
+    #[getset2(get_copy(pub), skip(get_ref))]
+    closed: bool,
+    /// 包含附件或图片。
+    #[getset2(get_copy(pub), skip(get_ref))]
+    attachment: bool,
+}
+
+/// 主题列表的一页。
+#[derive(Debug, Clone, PartialEq, Eq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct BoardPage {
+    #[getset2(get_copy(pub), skip(get_ref))]
+    page: u32,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    total_pages: u32,
+    types: Vec<ThreadType>,
+    threads: Vec<ThreadSummary>,
+}
+
+/// 主题列表的排序方式。
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum ThreadOrder {
+    /// 按最后回复时间。
+    #[default]
+    Latest,
+    /// 按发帖时间。
+    Created,
+    /// 按热度。
+    Hottest,
+    /// 只看精华，按最后回复时间。
+    Digest,
+}
+
+/// 主题列表的查询条件。
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ThreadListQuery {
+    pub fid: u64,
+    /// 主题分类，见 [`BoardPage::types`].
+    pub type_id: Option<u64>,
+    pub order: ThreadOrder,
+}
+impl ThreadListQuery {
+    #[inline]
+    pub fn new(fid: u64) -> Self {
+        Self {
+            fid,
+            ..Default::default()
+        }
+    }
+    /// Discuz 同时只接受一个 `filter`, 故 [`ThreadOrder::Digest`] 与主题分类同时给出时以主题分类为准。
+    fn extra_query(&self) -> String {
+        let orderby = match self.order {
+            ThreadOrder::Latest | ThreadOrder::Digest => "lastpost",
+            ThreadOrder::Created => "dateline",
+            ThreadOrder::Hottest => "heats",
+        };
+        match (self.type_id, self.order) {
+            (Some(type_id), _) => format!("filter=typeid&typeid={type_id}&orderby={orderby}"),
+            (None, ThreadOrder::Digest) => format!("filter=digest&digest=1&orderby={orderby}"),
+            (None, _) => format!("orderby={orderby}"),
+        }
+    }
+}
+
+fn parse_thread_types(html: &str) -> Vec<ThreadType> {
+    let Some(list) = find_between(html, "id=\"thread_types\"", "</ul>") else {
+        return Vec::new();
+    };
+    list.split("<li")
+        .filter_map(|item| {
+            let (href, text) = first_anchor(item)?;
+            let type_id = find_query_u64(href, "typeid")?;
+            let (name, threads) = match text.find("<span") {
+                Some(s) => (&text[..s], first_u64(&html_to_text(&text[s..]))),
+                None => (text, None),
+            };
+            Some(ThreadType {
+                type_id,
+                name: html_to_text(name),
+                threads,
+            })
+        })
+        .collect()
+}
+
+fn parse_thread_row(tid: u64, sticky: bool, row: &str) -> Option<ThreadSummary> {
+    let th = find_between(row, "<th", "</th>")?;
+    let type_name = th
+        .find("typeid=")
+        .and_then(|s| find_between(&th[s..], ">", "</a>"))
+        .map(html_to_text);
+    let subject = match th.find("xst\"") {
+        Some(s) => find_between(&th[s..], ">", "</a>")?,
+        None => th.split("<a ").skip(1).find_map(|a| {
+            let href = find_attr(a, "href").filter(|h| h.contains("tid="))?;
+            find_between(a, ">", "</a>").filter(|_| !href.contains("typeid="))
+        })?,
+    };
+    let mut by = row.split("<td class=\"by\"").skip(1);
+    let author_cell = by.next().unwrap_or_default();
+    let (author, author_uid) = match find_between(author_cell, "<cite>", "</cite>") {
+        Some(cite) => match first_anchor(cite) {
+            Some((href, name)) => (html_to_text(name), find_query_u64(href, "uid")),
+            None => (html_to_text(cite), None),
+        },
+        None => (String::new(), None),
+    };
+    let created = find_between(author_cell, "<em>", "</em>").and_then(find_discuz_time);
+    let last_reply = by.next().map(|cell| {
+        let author = find_between(cell, "<cite>", "</cite>")
+            .map(html_to_text)
+            .filter(|name| !name.is_empty());
+        let time = find_between(cell, "<em>", "</em>").and_then(find_discuz_time);
+        LastPost::new(Some(tid), None, author, time)
+    });
+    let (replies, views) = match find_between(row, "<td class=\"num\"", "</td>") {
+        Some(num) => (
+            find_between(num, "<a", "</a>")
+                .and_then(|a| first_u64(&a[a.find('>')? + 1..]))
+                .unwrap_or_default(),
+            find_between(num, "<em>", "</em>")
+                .and_then(first_u64)
+                .unwrap_or_default(),
+        ),
+        None => (0, 0),
+    };
+    let closed = find_between(th, "class=\"", "\"").is_some_and(|c| c.contains("lock"))
+        || row.contains("folder_lock")
+        || row.contains("title=\"关闭的主题");
+    Some(ThreadSummary {
+        tid,
+        subject: html_to_text(subject),
+        type_name,
+        author,
+        author_uid,
+        created,
+        replies,
+        views,
+        last_reply,
+        sticky,
+        digest: th.contains("alt=\"digest\""),
+        closed,
+        attachment: th.contains("alt=\"attachment\"") || th.contains("alt=\"attach_img\""),
+    })
+}
+
+pub(super) fn parse_board_page(html: &str, page: u32) -> Result<BoardPage, RSBBSError> {
+    check_discuz_page(html)?;
+    if !html.contains("id=\"threadlisttableid\"") && !html.contains("thread_") {
+        return Err(RSBBSError::ParseError(
+            "版块页面中没有主题列表。".to_owned(),
+        ));
+    }
+    let mut threads = Vec::new();
+    let mut rest = html;
+    while let Some(s) = rest.find("<tbody id=\"") {
+        rest = &rest[s + 11..];
+        let end = rest.find("</tbody>").unwrap_or(rest.len());
+        let row = &rest[..end];
+        let (sticky, id) = if let Some(id) = row.strip_prefix("stickthread_") {
+            (true, id)
+        } else if let Some(id) = row.strip_prefix("normalthread_") {
+            (false, id)
+        } else {
+            continue;
+        };
+        if let Some(thread) = leading_u64(id).and_then(|tid| parse_thread_row(tid, sticky, row)) {
+            threads.push(thread);
+        }
+        rest = &rest[end..];
+    }
+    Ok(BoardPage {
+        page,
+        total_pages: find_page_count(html),
+        types: parse_thread_types(html),
+        threads,
+    })
+}
+
+/// 逐页获取主题列表，直到最后一页为止。
+pub struct ThreadPages<'a> {
+    session: &'a RSBBSSession,
+    query: ThreadListQuery,
+    page: u32,
+    total_pages: Option<u32>,
+    finished: bool,
+}
+impl Iterator for ThreadPages<'_> {
+    type Item = Result<Vec<ThreadSummary>, RSBBSError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished || self.total_pages.is_some_and(|t| self.page > t) {
+            return None;
+        }
+        match self.session.board_page(&self.query, self.page) {
+            Ok(page) => {
+                self.total_pages = Some(page.total_pages);
+                self.page += 1;
+                if page.threads.is_empty() {
+                    self.finished = true;
+                    None
+                } else {
+                    Some(Ok(page.threads))
+                }
+            }
+            Err(e) => {
+                self.finished = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl RSBBSSession {
+    /// 获取主题列表的某一页，`page` 从 1 开始。
+    pub fn board_page(&self, query: &ThreadListQuery, page: u32) -> Result<BoardPage, RSBBSError> {
+        let html =
+            crate::protocol::rsbbs::forum_display(self, query.fid, page, &query.extra_query())?
+                .into_body()
+                .read_to_string()?;
+        parse_board_page(&html, page)
+    }
+    /// 按需逐页获取主题列表。
+    #[inline]
+    pub fn threads(&self, query: ThreadListQuery) -> ThreadPages<'_> {
+        ThreadPages {
+            session: self,
+            query,
+            page: 1,
+            total_pages: None,
+            finished: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOARD: &str = r##"
+<ul id="thread_types" class="ttp bm cl"><li id="ttp_all" class="a"><a href="forum.php?mod=forumdisplay&amp;fid=72">全部</a></li>
+<li><a href="forum.php?mod=forumdisplay&amp;fid=72&amp;filter=typeid&amp;typeid=5">出售<span class="xg1 num">120</span></a></li></ul>
+<table summary="forum_72" cellspacing="0" cellpadding="0" id="threadlisttableid">
+<tbody id="stickthread_100"><tr><td class="icn"><a href="forum.php?mod=viewthread&amp;tid=100" title="全局置顶主题 - 新窗口打开"><img src="static/image/common/pin_3.gif" /></a></td>
+<th class="common"><em>[<a href="forum.php?mod=forumdisplay&amp;fid=72&amp;filter=typeid&amp;typeid=5">出售</a>]</em>
+<a href="forum.php?mod=viewthread&amp;tid=100&amp;extra=page%3D1" onclick="atarget(this)" class="s xst">版规 &amp; 须知</a>
+<img src="static/image/filetype/image_s.gif" alt="attach_img" title="图片附件" align="absmiddle" />
+<img src="static/image/common/digest_1.gif" align="absmiddle" alt="digest" title="精华 1" /></th>
+<td class="by"><cite><a href="home.php?mod=space&amp;uid=3" c="1">admin2024</a></cite><em><span class="xi1">2023-9-1</span></em></td>
+<td class="num"><a href="forum.php?mod=viewthread&amp;tid=100" class="xi2">1,024</a><em>65536</em></td>
+<td class="by"><cite><a href="home.php?mod=space&amp;username=bob" c="1">bob</a></cite><em><a href="forum.php?mod=redirect&amp;tid=100&amp;goto=lastpost#lastpost"><span title="2024-3-5 08:07">3 天前</span></a></em></td></tr></tbody>
+<tbody id="separatorline"><tr><th>版块主题</th></tr></tbody>
+<tbody id="normalthread_101"><tr><td class="icn"><a href="forum.php?mod=viewthread&amp;tid=101" title="关闭的主题 - 新窗口打开"><img src="static/image/common/folder_lock.gif" /></a></td>
+<th class="lock"><a href="forum.php?mod=viewthread&amp;tid=101" class="s xst">出 自行车</a></th>
+<td class="by"><cite>匿名</cite><em><span>2024-1-2</span></em></td>
+<td class="num"><a href="#" class="xi2">0</a><em>7</em></td>
+<td class="by"><cite>匿名</cite><em><a href="#">2024-1-2 10:00</a></em></td></tr></tbody>
+</table><div class="pg"><strong>1</strong><label><span title="共 3 页"> / 3 页</span></label></div>"##;
+
+    #[test]
+    fn test_parse_board_page() {
+        let page = parse_board_page(BOARD, 1).unwrap();
+        assert_eq!(page.total_pages(), 3);
+        assert_eq!(page.types()[0].type_id(), 5);
+        assert_eq!(page.types()[0].name(), "出售");
+        assert_eq!(page.types()[0].threads(), Some(120));
+        let [sticky, closed] = &page.threads()[..] else {
+            panic!("{:?}", page.threads());
+        };
+        assert_eq!(sticky.tid(), 100);
+        assert_eq!(sticky.subject(), "版规 & 须知");
+        assert_eq!(sticky.type_name().as_deref(), Some("出售"));
+        assert_eq!(
+            (sticky.author().as_str(), sticky.author_uid()),
+            ("admin2024", Some(3))
+        );
+        assert!(sticky.created().is_some());
+        assert_eq!((sticky.replies(), sticky.views()), (1024, 65536));
+        assert!(sticky.sticky() && sticky.digest() && sticky.attachment() && !sticky.closed());
+        let last = sticky.last_reply().as_ref().unwrap();
+        assert_eq!(last.author().as_deref(), Some("bob"));
+        assert!(last.time().is_some());
+        assert_eq!(closed.author(), "匿名");
+        assert!(closed.closed() && !closed.sticky() && !closed.digest());
+        assert_eq!(closed.views(), 7);
+    }
+
+    #[test]
+    fn test_extra_query() {
+        let mut query = ThreadListQuery::new(72);
+        assert_eq!(query.extra_query(), "orderby=lastpost");
+        query.order = ThreadOrder::Digest;
+        assert_eq!(
+            query.extra_query(),
+            "filter=digest&digest=1&orderby=lastpost"
+        );
+        query.type_id = Some(5);
+        query.order = ThreadOrder::Hottest;
+        assert_eq!(query.extra_query(), "filter=typeid&typeid=5&orderby=heats");
+    }
+}
@@ -0,0 +1,280 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    RSBBSSession,
+    error::RSBBSError,
+    utils::rsbbs::{
+        find_attr, find_between, find_discuz_message, find_discuz_time, find_query_u64, first_u64,
+        html_to_text,
+    },
+};
+use chrono::NaiveDateTime;
+use getset2::Getset2;
+
+/// 版块的最后发表信息。
+#[derive(Debug, Clone, Default, PartialEq, Eq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct LastPost {
+    #[getset2(get_copy(pub), skip(get_ref))]
+    tid: Option<u64>,
+    subject: Option<String>,
+    author: Option<String>,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    time: Option<NaiveDateTime>,
+}
+
+/// 版块。
+#[derive(Debug, Clone, PartialEq, Eq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct Board {
+    #[getset2(get_copy(pub), skip(get_ref))]
+    fid: u64,
+    name: String,
+    description: Option<String>,
+    /// 主题数。
+    #[getset2(get_copy(pub), skip(get_ref))]
+    threads: u64,
+    /// 帖子数。
+    #[getset2(get_copy(pub), skip(get_ref))]
+    posts: u64,
+    /// 今日发帖数。
+    #[getset2(get_copy(pub), skip(get_ref))]
+    today_posts: u64,
+    last_post: Option<LastPost>,
+}
+
+/// 分区，包含若干版块。
+#[derive(Debug, Clone, PartialEq, Eq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct ForumCategory {
+    #[getset2(get_copy(pub), skip(get_ref))]
+    gid: u64,
+    name: String,
+    boards: Vec<Board>,
+}
+
+/// 链接是否指向版块，兼容伪静态地址 `forum-{fid}-{page}.html`.
+pub(super) fn board_fid(href: &str) -> Option<u64> {
+    if href.contains("mod=forumdisplay") {
+        find_query_u64(href, "fid")
+    } else {
+        href.strip_prefix("forum-")
+            .and_then(crate::utils::rsbbs::leading_u64)
+    }
+}
+
+/// 片段中第一个 `<a>` 的链接与文本。
+pub(super) fn first_anchor(html: &str) -> Option<(&str, &str)> {
+    let s = html.find("<a ")?;
+    let html = &html[s..];
+    let href = find_attr(html, "href")?;
+    let text = find_between(html, ">", "</a>")?;
+    Some((href, text))
+}
+
+/// 页面是否为 Discuz 的提示页面，如未登录、无权访问、主题不存在等。
+pub(super) fn check_discuz_page(html: &str) -> Result<(), RSBBSError> {
+    match find_discuz_message(html) {
+        Some(msg) if msg.contains("登录") => Err(RSBBSError::NotLoggedIn),
+        Some(msg) => Err(RSBBSError::ServerError(msg)),
+        None => Ok(()),
+    }
+}
+
+fn parse_last_post(html: &str) -> Option<LastPost> {
+    let s = html.find("goto=lastpost")?;
+    let html = &html[html[..s].rfind("<a ")?..];
+    let end = html.find("</td>").or_else(|| html.find("</dd>"));
+    let html = &html[..end.unwrap_or(html.len())];
+    let (href, text) = first_anchor(html)?;
+    let subject = html_to_text(text);
+    let subject = (!subject.is_empty() && !subject.starts_with("最后发表")).then_some(subject);
+    let author = html
+        .find("<cite>")
+        .and_then(|s| first_anchor(&html[s..]))
+        .map(|(_, name)| html_to_text(name))
+        .filter(|name| !name.is_empty());
+    Some(LastPost {
+        tid: find_query_u64(href, "tid"),
+        subject,
+        author,
+        time: find_discuz_time(html),
+    })
+}
+
+fn parse_board(html: &str) -> Option<Board> {
+    let (href, name) = first_anchor(html)?;
+    let fid = board_fid(href)?;
+    let today_posts = find_between(html, "title=\"今日\"", "</em>")
+        .and_then(|s| first_u64(&s[s.find('>')? + 1..]))
+        .unwrap_or_default();
+    let description = find_between(html, "<p class=\"xg2\">", "</p>")
+        .map(html_to_text)
+        .filter(|d| !d.is_empty());
+    let (threads, posts) = if let Some(counts) = find_between(html, "class=\"fl_i\"", "</td>") {
+        // 表格布局：`<span class="xi2">主题</span><span class="xg1"> / 帖数</span>`.
+        let counts = html_to_text(&counts[counts.find('>')? + 1..]);
+        let mut counts = counts.split('/').filter_map(first_u64);
+        (counts.next()?, counts.next().unwrap_or_default())
+    } else {
+        // 网格布局：`<em>主题: 123</em>, <em>帖数: 456</em>`.
+        let count_after = |key: &str| {
+            html.find(key)
+                .and_then(|s| first_u64(&html_to_text(&html[s + key.len()..])))
+                .unwrap_or_default()
+        };
+        (count_after("主题"), count_after("帖数"))
+    };
+    Some(Board {
+        fid,
+        name: html_to_text(name),
+        description,
+        threads,
+        posts,
+        today_posts,
+        last_post: parse_last_post(html),
+    })
+}
+
+/// 分区内每个版块的起始位置，版块标题位于 `<h2>` (表格布局) 或 `<dt>` (网格布局) 中。
+fn board_starts(html: &str) -> Vec<usize> {
+    let mut starts = Vec::new();
+    for ident in ["<h2><a ", "<dt><a "] {
+        let mut offset = 0;
+        while let Some(s) = html[offset..].find(ident) {
+            let s = offset + s;
+            if find_attr(&html[s + 4..], "href").is_some_and(|h| board_fid(h).is_some()) {
+                starts.push(s);
+            }
+            offset = s + ident.len();
+        }
+    }
+    starts.sort_unstable();
+    starts
+}
+
+pub(super) fn parse_forum_index(html: &str) -> Result<Vec<ForumCategory>, RSBBSError> {
+    const CATEGORY: &str = "href=\"forum.php?gid=";
+    let mut headers = Vec::new();
+    let mut offset = 0;
+    while let Some(s) = html[offset..].find(CATEGORY) {
+        headers.push(offset + s);
+        offset += s + CATEGORY.len();
+    }
+    if headers.is_empty() {
+        return Err(RSBBSError::ParseError("论坛首页中没有分区。".to_owned()));
+    }
+    let mut categories = Vec::with_capacity(headers.len());
+    for (i, &s) in headers.iter().enumerate() {
+        let block = &html[s..headers.get(i + 1).copied().unwrap_or(html.len())];
+        let Some(gid) = crate::utils::rsbbs::leading_u64(&block[CATEGORY.len()..]) else {
+            continue;
+        };
+        let name = find_between(block, ">", "</a>")
+            .map(html_to_text)
+            .unwrap_or_default();
+        let starts = board_starts(block);
+        let boards = starts
+            .iter()
+            .enumerate()
+            .filter_map(|(j, &b)| {
+                parse_board(&block[b..starts.get(j + 1).copied().unwrap_or(block.len())])
+            })
+            .collect();
+        categories.push(ForumCategory { gid, name, boards });
+    }
+    Ok(categories)
+}
+
+impl LastPost {
+    #[inline]
+    pub(super) fn new(
+        tid: Option<u64>,
+        subject: Option<String>,
+        author: Option<String>,
+        time: Option<NaiveDateTime>,
+    ) -> Self {
+        Self {
+            tid,
+            subject,
+            author,
+            time,
+        }
+    }
+}
+
+impl RSBBSSession {
+    /// 论坛首页的分区与版块。
+    pub fn forums(&self) -> Result<Vec<ForumCategory>, RSBBSError> {
+        let html = crate::protocol::rsbbs::forum_index(self)?
+            .into_body()
+            .read_to_string()?;
+        parse_forum_index(&html)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INDEX: &str = r#"
+<div class="bm bmw flg cl"><div class="bm_h cl"><h2><a href="forum.php?gid=1" style="">校园生活</a></h2></div>
+<div id="category_1" class="bm_c"><table cellspacing="0" cellpadding="0" class="fl_tb">
+<tr><td class="fl_icn"><a href="forum.php?mod=forumdisplay&amp;fid=72"><img src="x.png" /></a></td>
+<td><h2><a href="forum.php?mod=forumdisplay&amp;fid=72" style="">二手市场</a><em class="xw0 xi1" title="今日"> (12)</em></h2>
+<p class="xg2">闲置&amp;交换</p><p>版主: <span class="xi2"><a href="home.php?mod=space&amp;username=abc">abc</a></span></p></td>
+<td class="fl_i"><span class="xi2">1,234</span><span class="xg1"> / 56789</span></td>
+<td class="fl_by"><div><a href="forum.php?mod=redirect&amp;tid=100&amp;goto=lastpost#lastpost" class="xi2">出 自行车</a>
+<cite><span title="2024-3-5 08:07">3&nbsp;天前</span> <a href="home.php?mod=space&amp;username=seller">seller</a></cite></div></td></tr>
+</table></div></div>
+<div class="bm bmw flg cl"><div class="bm_h cl"><h2><a href="forum.php?gid=2">技术交流</a></h2></div>
+<div id="category_2" class="bm_c"><table class="fl_tb"><tr>
+<td class="fl_g"><dl><dt><a href="forum-3-1.html">Linux</a></dt>
+<dd><em>主题: 10</em>, <em>帖数: 20</em></dd>
+<dd><a href="forum.php?mod=redirect&amp;tid=5&amp;goto=lastpost#lastpost">最后发表: <span title="2024-1-2 3:04">2024-1-2</span></a></dd></dl></td>
+</tr></table></div></div>"#;
+
+    #[test]
+    fn test_parse_forum_index() {
+        let categories = parse_forum_index(INDEX).unwrap();
+        assert_eq!(categories.len(), 2);
+        let board = &categories[0].boards()[0];
+        assert_eq!(categories[0].name(), "校园生活");
+        assert_eq!(board.fid(), 72);
+        assert_eq!(board.name(), "二手市场");
+        assert_eq!(board.description().as_deref(), Some("闲置&交换"));
+        assert_eq!(
+            (board.threads(), board.posts(), board.today_posts()),
+            (1234, 56789, 12)
+        );
+        let last = board.last_post().as_ref().unwrap();
+        assert_eq!(last.tid(), Some(100));
+        assert_eq!(last.subject().as_deref(), Some("出 自行车"));
+        assert_eq!(last.author().as_deref(), Some("seller"));
+        assert!(last.time().is_some());
+        let board = &categories[1].boards()[0];
+        assert_eq!((board.fid(), board.threads(), board.posts()), (3, 10, 20));
+        let last = board.last_post().as_ref().unwrap();
+        assert_eq!((last.tid(), last.subject()), (Some(5), &None));
+    }
+}
@@ -20,6 +20,18 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+mod board;
+mod forum;
+mod pm;
+mod post;
+mod thread;
+
+pub use board::*;
+pub use forum::*;
+pub use pm::*;
+pub use post::*;
+pub use thread::*;
+
 use crate::error::{CaptchaError, LoginError};
 use crate::utils::rsbbs::{find_id_hash, find_vcode_img_url};
 use crate::{
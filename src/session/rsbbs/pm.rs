@@ -0,0 +1,334 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    RSBBSSession,
+    error::RSBBSError,
+    session::rsbbs::{
+        check_discuz_page, check_post_page, find_formhash, first_anchor, parse_ajax_result,
+    },
+    utils::rsbbs::{
+        find_between, find_discuz_time, find_page_count, find_query_u64, first_u64, html_to_text,
+        leading_u64,
+    },
+};
+use chrono::NaiveDateTime;
+use getset2::Getset2;
+
+/// 私人消息会话。
+#[derive(Debug, Clone, PartialEq, Eq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct PmConversation {
+    /// 对方的 uid.
+    #[getset2(get_copy(pub), skip(get_ref))]
+    uid: u64,
+    username: String,
+    /// 最后一条消息的摘要。
+    last_message: String,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    time: Option<NaiveDateTime>,
+    /// 消息条数，页面未显示时为 `None`.
+    #[getset2(get_copy(pub), skip(get_ref))]
+    count: Option<u64>,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    unread: bool,
+}
+
+/// 会话中的一条消息。
+#[derive(Debug, Clone, PartialEq, Eq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct PmMessage {
+    #[getset2(get_copy(pub), skip(get_ref))]
+    pmid: u64,
+    author: String,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    author_uid: Option<u64>,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    time: Option<NaiveDateTime>,
+    html: String,
+    text: String,
+}
+
+/// 私人消息的收件人。
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PmRecipient<'a> {
+    Uid(u64),
+    Username(&'a str),
+}
+
+/// 发送私人消息的表单，返回 `touid` 与表单字段。
+fn send_pm_form<'a>(
+    to: PmRecipient<'a>,
+    formhash: &'a str,
+    message: &'a str,
+) -> (Option<u64>, Vec<(&'static str, &'a str)>) {
+    let mut form = vec![
+        ("formhash", formhash),
+        ("message", message),
+        ("handlekey", "pmsend"),
+        ("pmsubmit", "true"),
+    ];
+    let touid = match to {
+        PmRecipient::Uid(uid) => Some(uid),
+        PmRecipient::Username(name) => {
+            form.push(("username", name));
+            None
+        }
+    };
+    (touid, form)
+}
+
+/// 以 `ident` 后跟数字 id 为起点，将页面切分为若干块。
+fn id_blocks<'a>(html: &'a str, ident: &str) -> Vec<(u64, &'a str)> {
+    let mut starts = Vec::new();
+    let mut offset = 0;
+    while let Some(s) = html[offset..].find(ident) {
+        let s = offset + s;
+        offset = s + ident.len();
+        let id = &html[offset..];
+        let digits = id.len() - id.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        if id[digits..].starts_with('"')
+            && let Some(id) = leading_u64(id)
+        {
+            starts.push((html[..s].rfind('<').unwrap_or(s), id));
+        }
+    }
+    starts
+        .iter()
+        .enumerate()
+        .map(|(i, &(s, id))| {
+            let end = starts.get(i + 1).map_or(html.len(), |&(e, _)| e);
+            (id, &html[s..end])
+        })
+        .collect()
+}
+
+fn parse_conversation(uid: u64, block: &str) -> PmConversation {
+    let unread = find_between(block, "class=\"", "\"").is_some_and(|c| c.contains("newpm"));
+    // 跳过头像与 `<div class="o">` 中的删除链接。
+    let body = block.find("pm_c").map_or(block, |s| &block[s..]);
+    let body = match body.split_once("class=\"o\"") {
+        Some((_, o)) => o.find("</div>").map_or(o, |e| &o[e + 6..]),
+        None => body,
+    };
+    let (name_end, username) = match body.find("class=\"xw1\"") {
+        Some(s) => {
+            let name = find_between(&body[s..], ">", "</a>").unwrap_or_default();
+            (
+                s + body[s..].find("</a>").map_or(0, |e| e + 4),
+                html_to_text(name),
+            )
+        }
+        None => match first_anchor(body) {
+            Some((_, name)) => (body.find("</a>").map_or(0, |e| e + 4), html_to_text(name)),
+            None => (0, String::new()),
+        },
+    };
+    let info = body
+        .find("class=\"xg1\"")
+        .map_or(body.len(), |i| body[..i].rfind('<').unwrap_or(i));
+    let summary = html_to_text(&body[name_end.min(info)..info]);
+    let summary = summary
+        .split_once(':')
+        .filter(|(head, _)| head.ends_with("说 ") || head.ends_with('说'))
+        .map_or(summary.as_str(), |(_, rest)| rest)
+        .trim()
+        .to_owned();
+    let info = &body[info..];
+    PmConversation {
+        uid,
+        username,
+        last_message: summary,
+        time: find_discuz_time(info),
+        count: find_between(info, "共", "条").and_then(first_u64),
+        unread,
+    }
+}
+
+pub(super) fn parse_pm_list(html: &str) -> Result<(Vec<PmConversation>, u32), RSBBSError> {
+    check_discuz_page(html)?;
+    let conversations = id_blocks(html, "id=\"pmlist_")
+        .into_iter()
+        .map(|(uid, block)| parse_conversation(uid, block))
+        .collect();
+    Ok((conversations, find_page_count(html)))
+}
+
+fn parse_message(pmid: u64, block: &str) -> PmMessage {
+    let content = find_between(block, "class=\"pm_c\"", "</dd>")
+        .or_else(|| find_between(block, "class=\"pm_c\"", "</div>"))
+        .map(|c| &c[c.find('>').map_or(0, |e| e + 1)..])
+        .unwrap_or_default();
+    let header = &block[..block.find("class=\"pm_c\"").unwrap_or(block.len())];
+    let (author, author_uid) = header
+        .match_indices("<a ")
+        .find_map(|(s, _)| {
+            let (href, name) = first_anchor(&header[s..])?;
+            let name = html_to_text(name);
+            (!name.is_empty()).then(|| (name, find_query_u64(href, "uid")))
+        })
+        .unwrap_or_default();
+    PmMessage {
+        pmid,
+        author,
+        author_uid,
+        time: find_discuz_time(header),
+        html: content.trim().to_owned(),
+        text: html_to_text(content),
+    }
+}
+
+pub(super) fn parse_pm_view(html: &str) -> Result<(Vec<PmMessage>, u32), RSBBSError> {
+    check_discuz_page(html)?;
+    let messages = id_blocks(html, "id=\"pm_")
+        .into_iter()
+        .map(|(pmid, block)| parse_message(pmid, block))
+        .collect();
+    Ok((messages, find_page_count(html)))
+}
+
+impl RSBBSSession {
+    /// 私人消息会话列表的某一页，`page` 从 1 开始，同时返回总页数。
+    pub fn pm_conversation_page(
+        &self,
+        page: u32,
+    ) -> Result<(Vec<PmConversation>, u32), RSBBSError> {
+        let html = crate::protocol::rsbbs::pm_list(self, page)?
+            .into_body()
+            .read_to_string()?;
+        parse_pm_list(&html)
+    }
+    /// 所有私人消息会话。
+    pub fn pm_conversations(&self) -> Result<Vec<PmConversation>, RSBBSError> {
+        let (mut conversations, total_pages) = self.pm_conversation_page(1)?;
+        for page in 2..=total_pages {
+            conversations.extend(self.pm_conversation_page(page)?.0);
+        }
+        Ok(conversations)
+    }
+    /// 与 `uid` 的所有消息，按时间先后排列。
+    pub fn pm_messages(&self, uid: u64) -> Result<Vec<PmMessage>, RSBBSError> {
+        let mut messages = Vec::new();
+        let mut page = 1;
+        loop {
+            let html = crate::protocol::rsbbs::pm_view(self, uid, page)?
+                .into_body()
+                .read_to_string()?;
+            let (mut page_messages, total_pages) = parse_pm_view(&html)?;
+            messages.append(&mut page_messages);
+            if page >= total_pages {
+                break;
+            }
+            page += 1;
+        }
+        Ok(messages)
+    }
+    /// 发送私人消息。
+    pub fn send_pm(&self, to: PmRecipient, message: &str) -> Result<(), RSBBSError> {
+        let referer = crate::protocol::rsbbs::pm_compose_url();
+        let html = crate::protocol::rsbbs::post_page(self, &referer)?
+            .into_body()
+            .read_to_string()?;
+        check_post_page(&html, &html)?;
+        let formhash = find_formhash(&html)
+            .ok_or_else(|| RSBBSError::ParseError("页面中没有 `formhash`.".to_owned()))?;
+        let (touid, form) = send_pm_form(to, formhash, message);
+        let text = crate::protocol::rsbbs::send_pm(self, touid, &referer, &form)?
+            .into_body()
+            .read_to_string()?;
+        parse_ajax_result(&text).map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compose_page_errors() {
+        let flood = r#"<div id="messagetext" class="alert_error"><p>抱歉，您两次发送短消息间隔少于 10 秒，请稍候再发送</p></div>"#;
+        assert!(matches!(
+            check_post_page(flood, flood),
+            Err(RSBBSError::FloodControl(Some(10)))
+        ));
+        let compose = r#"<form id="pmform" method="post"><input type="hidden" name="formhash" value="a1b2c3d4" /><input name="seccodeverify" /></form>"#;
+        assert!(matches!(
+            check_post_page(compose, compose),
+            Err(RSBBSError::SeccodeRequired)
+        ));
+    }
+    #[test]
+    fn test_send_pm_form() {
+        let (touid, form) = send_pm_form(PmRecipient::Username("alice"), "a1b2c3d4", "你好");
+        assert_eq!(touid, None);
+        assert!(form.contains(&("username", "alice")));
+        let url = crate::protocol::rsbbs::send_pm_url(touid);
+        assert!(!url.contains("touid"));
+        assert!(url.contains("op=send&pmsubmit=yes"));
+        let (touid, form) = send_pm_form(PmRecipient::Uid(123), "a1b2c3d4", "你好");
+        assert!(!form.iter().any(|(name, _)| *name == "username"));
+        assert!(crate::protocol::rsbbs::send_pm_url(touid).contains("&touid=123&"));
+    }
+    #[test]
+    fn test_parse_pm_list() {
+        let html = r#"<form id="deletepmform"><dl id="pmlist_123" class="bbda cl newpm">
+<dd class="m avt"><a href="home.php?mod=space&amp;uid=123"><img src="a.png" /></a></dd>
+<dd class="ptm pm_c"><div class="o"><a href="home.php?mod=spacecp&amp;ac=pm&amp;op=delete&amp;deletepm_deluid[]=123">删除</a></div>
+<a href="home.php?mod=space&amp;uid=123" target="_blank" class="xw1">alice</a> 对您说 :<br />
+周末社团活动改到周日
+<div class="xg1"><span title="2024-3-5 08:07">3 天前</span><span class="pipe">|</span> 共 5 条 <span class="pipe">|</span>
+<a href="home.php?mod=space&amp;do=pm&amp;subop=view&amp;touid=123#last" id="pmlist_123_a">回复</a></div></dd></dl></form>
+<div class="pg"><span title="共 2 页"> / 2 页</span></div>"#;
+        let (conversations, pages) = parse_pm_list(html).unwrap();
+        assert_eq!(pages, 2);
+        let [c] = &conversations[..] else {
+            panic!("{conversations:?}");
+        };
+        assert_eq!((c.uid(), c.username().as_str()), (123, "alice"));
+        assert_eq!(c.last_message(), "周末社团活动改到周日");
+        assert_eq!((c.count(), c.unread()), (Some(5), true));
+        assert!(c.time().is_some());
+    }
+
+    #[test]
+    fn test_parse_pm_view() {
+        let html = r#"<div id="pm_ul"><dl id="pm_9001" class="bbda cl">
+<dd class="m avt"><a href="home.php?mod=space&amp;uid=123"><img src="a.png" /></a></dd>
+<dt><a href="home.php?mod=space&amp;uid=123" class="xw1">alice</a> <span class="xg1 xw0">2024-3-5 08:07</span></dt>
+<dd class="pm_c">在吗？<br />有事找你</dd></dl>
+<dl id="pm_9002" class="bbda cl"><dt><a href="home.php?mod=space&amp;uid=456" class="xw1">me</a> <span class="xg1">2024-3-5 09:00</span></dt>
+<dd class="pm_c">在</dd></dl></div>"#;
+        let (messages, pages) = parse_pm_view(html).unwrap();
+        assert_eq!(pages, 1);
+        let [first, second] = &messages[..] else {
+            panic!("{messages:?}");
+        };
+        assert_eq!((first.pmid(), first.author().as_str()), (9001, "alice"));
+        assert_eq!(first.author_uid(), Some(123));
+        assert_eq!(first.text(), "在吗？\n有事找你");
+        assert!(first.time().is_some());
+        assert_eq!(
+            (second.author_uid(), second.text().as_str()),
+            (Some(456), "在")
+        );
+    }
+}
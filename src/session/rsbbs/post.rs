@@ -0,0 +1,299 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    RSBBSSession,
+    error::RSBBSError,
+    session::rsbbs::check_discuz_page,
+    utils::{
+        find_id_value_pair,
+        rsbbs::{decode_entities, find_between, find_query_u64, first_u64, html_to_text},
+    },
+};
+use getset2::Getset2;
+
+/// 发表成功后的主题与帖子。
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Getset2)]
+#[getset2(get_copy(pub))]
+pub struct Posted {
+    /// 主题的 tid, 发表新主题且服务器未返回跳转地址时为 `None`.
+    tid: Option<u64>,
+    /// 新帖子的 pid, 服务器未返回时为 `None`.
+    pid: Option<u64>,
+}
+
+/// 将 Discuz 的提示信息转为对应的错误。
+pub(super) fn discuz_error(msg: String) -> RSBBSError {
+    if msg.contains("间隔") || msg.contains("秒后") {
+        RSBBSError::FloodControl(first_u64(&msg).map(|n| n as u32))
+    } else if msg.contains("验证码") || msg.contains("验证问答") {
+        RSBBSError::SeccodeRequired
+    } else if msg.contains("登录") {
+        // 游客的提示如“您无权进行当前操作，这可能是因为您还没有登录”也包含权限关键字。
+        RSBBSError::NotLoggedIn
+    } else if ["权限", "无权", "没有权", "禁止", "用户组"]
+        .iter()
+        .any(|k| msg.contains(k))
+    {
+        RSBBSError::PermissionDenied(msg)
+    } else {
+        RSBBSError::ServerError(msg)
+    }
+}
+
+/// 回调函数的第一个参数，即 `('...'` 中的字符串，处理其中 `\'` 等转义。
+fn first_js_arg(call: &str) -> Option<String> {
+    let mut chars = call[call.find("('")? + 2..].chars();
+    let mut arg = String::new();
+    loop {
+        match chars.next()? {
+            '\\' => arg.push(chars.next()?),
+            '\'' => return Some(arg),
+            c => arg.push(c),
+        }
+    }
+}
+
+/// 没有回调函数时只能依据提示文字判断，“发送不成功”“未成功”等失败提示同样包含“成功”。
+fn is_success_message(msg: &str) -> bool {
+    msg.contains("成功") && !["不成功", "未成功", "失败"].iter().any(|k| msg.contains(k))
+}
+
+/// 解析 `inajax=1` 请求的响应，成功时返回跳转地址。
+///
+/// ``` text
+/// <root><![CDATA[... succeedhandle_postform('url', 'msg', {...}); ...]]></root>
+/// <root><![CDATA[... errorhandle_postform('msg', {...}); ...]]></root>
+/// ```
+pub(super) fn parse_ajax_result(text: &str) -> Result<String, RSBBSError> {
+    let cdata = find_between(text, "<![CDATA[", "]]>").unwrap_or(text);
+    if let Some(s) = cdata.find("succeedhandle_") {
+        let url = first_js_arg(&cdata[s..]).unwrap_or_default();
+        return Ok(decode_entities(&url));
+    }
+    if let Some(s) = cdata.find("errorhandle_") {
+        let msg = first_js_arg(&cdata[s..]).unwrap_or_default();
+        return Err(discuz_error(html_to_text(&msg)));
+    }
+    let msg = html_to_text(cdata);
+    if msg.is_empty() {
+        Err(RSBBSError::ParseError("服务器没有返回结果。".to_owned()))
+    } else if is_success_message(&msg) {
+        Ok(String::new())
+    } else {
+        Err(discuz_error(msg))
+    }
+}
+
+/// 表单中的隐藏字段，如 `formhash`, `posttime` 等。
+///
+/// 与登录相同，逐个 `<input ` 读取 `name` 与 `value`.
+pub(super) fn hidden_inputs<'a>(
+    form_id: &str,
+    html: &'a str,
+) -> Result<Vec<(&'a str, &'a str)>, RSBBSError> {
+    let form = find_between(html, &format!("id=\"{form_id}\""), "</form>")
+        .ok_or_else(|| RSBBSError::ParseError(format!("页面中没有表单 `{form_id}`.")))?;
+    let inputs = form
+        .split("<input ")
+        .skip(1)
+        .filter(|s| s[..s.find('>').unwrap_or(s.len())].contains("type=\"hidden\""))
+        .filter_map(|s| find_id_value_pair(&["name=\""], s).ok())
+        .collect::<Vec<_>>();
+    if !inputs.iter().any(|(name, _)| *name == "formhash") {
+        return Err(RSBBSError::ParseError("表单中没有 `formhash`.".to_owned()));
+    }
+    Ok(inputs)
+}
+
+/// 页面中任一 `formhash` 字段的值。
+pub(super) fn find_formhash(html: &str) -> Option<&str> {
+    html.split("<input ")
+        .skip(1)
+        .filter_map(|s| find_id_value_pair(&["name=\""], s).ok())
+        .find_map(|(name, value)| (name == "formhash").then_some(value))
+}
+
+/// 检查发帖、回复或发送私人消息的页面，`form` 为其中的表单。
+///
+/// 提示信息按 [`discuz_error`] 转为对应的错误，表单需要验证码时返回 [`RSBBSError::SeccodeRequired`].
+pub(super) fn check_post_page(html: &str, form: &str) -> Result<(), RSBBSError> {
+    check_discuz_page(html).map_err(|e| match e {
+        RSBBSError::ServerError(msg) => discuz_error(msg),
+        e => e,
+    })?;
+    if form.contains("name=\"seccodeverify\"") || form.contains("name=\"secanswer\"") {
+        return Err(RSBBSError::SeccodeRequired);
+    }
+    Ok(())
+}
+
+/// 打开发帖或回复页面，检查是否需要验证码，返回页面与其地址。
+fn open_post_page(session: &RSBBSSession, url: String) -> Result<(String, String), RSBBSError> {
+    let html = crate::protocol::rsbbs::post_page(session, &url)?
+        .into_body()
+        .read_to_string()?;
+    check_post_page(
+        &html,
+        find_between(&html, "id=\"postform\"", "</form>").unwrap_or_default(),
+    )?;
+    Ok((html, url))
+}
+
+/// 从跳转地址中读取 tid 与 pid, 服务器未返回地址时 `url` 为空。
+fn posted(url: &str, tid: Option<u64>) -> Posted {
+    Posted {
+        tid: find_query_u64(url, "tid").or(tid),
+        pid: find_query_u64(url, "pid"),
+    }
+}
+
+impl RSBBSSession {
+    /// 回复主题，`quote` 为要引用的帖子的 pid.
+    pub fn reply(&self, tid: u64, message: &str, quote: Option<u64>) -> Result<Posted, RSBBSError> {
+        let (html, referer) =
+            open_post_page(self, crate::protocol::rsbbs::reply_page_url(tid, quote))?;
+        let mut form = hidden_inputs("postform", &html)?;
+        // 引用时服务器会在编辑框中预先填入 `[quote]...[/quote]`.
+        let quoted = find_between(&html, "name=\"message\"", "</textarea>")
+            .and_then(|t| Some(decode_entities(&t[t.find('>')? + 1..])))
+            .filter(|q| !q.trim().is_empty());
+        let message = match quoted {
+            Some(quoted) => format!("{}\n{message}", quoted.trim_end()),
+            None => message.to_owned(),
+        };
+        form.retain(|(name, _)| !["message", "subject"].contains(name));
+        form.push(("message", &message));
+        form.push(("subject", ""));
+        let text = crate::protocol::rsbbs::submit_reply(self, tid, &referer, &form)?
+            .into_body()
+            .read_to_string()?;
+        Ok(posted(&parse_ajax_result(&text)?, Some(tid)))
+    }
+    /// 在版块中发表新主题，`type_id` 为主题分类，见 [`BoardPage::types`](crate::BoardPage::types).
+    pub fn new_thread(
+        &self,
+        fid: u64,
+        subject: &str,
+        message: &str,
+        type_id: Option<u64>,
+    ) -> Result<Posted, RSBBSError> {
+        let (html, referer) =
+            open_post_page(self, crate::protocol::rsbbs::new_thread_page_url(fid))?;
+        let mut form = hidden_inputs("postform", &html)?;
+        let type_id = type_id.map(|t| t.to_string());
+        form.retain(|(name, _)| !["message", "subject", "typeid"].contains(name));
+        form.push(("subject", subject));
+        form.push(("message", message));
+        if let Some(type_id) = &type_id {
+            form.push(("typeid", type_id));
+        }
+        let text = crate::protocol::rsbbs::submit_new_thread(self, fid, &referer, &form)?
+            .into_body()
+            .read_to_string()?;
+        Ok(posted(&parse_ajax_result(&text)?, None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ajax_result() {
+        let ok = r#"<?xml version="1.0" encoding="utf-8"?><root><![CDATA[<script type="text/javascript" reload="1">if(typeof succeedhandle_postform=='function') {succeedhandle_postform('forum.php?mod=viewthread&amp;tid=100&amp;pid=201&amp;page=1&amp;extra=#pid201', '非常感谢，回复发布成功', {'fid':'72'});}</script>]]></root>"#;
+        let posted = posted(&parse_ajax_result(ok).unwrap(), None);
+        assert_eq!((posted.tid(), posted.pid()), (Some(100), Some(201)));
+        let flood = r#"<root><![CDATA[<script>errorhandle_postform('抱歉，您两次发表间隔少于 15 秒，请稍候再发表', {});</script>]]></root>"#;
+        assert!(matches!(
+            parse_ajax_result(flood),
+            Err(RSBBSError::FloodControl(Some(15)))
+        ));
+        let denied = r#"<root><![CDATA[<script>errorhandle_postform('抱歉，您所在的用户组(新手上路)无法发帖', {});</script>]]></root>"#;
+        assert!(matches!(
+            parse_ajax_result(denied),
+            Err(RSBBSError::PermissionDenied(_))
+        ));
+        let seccode = r#"<root><![CDATA[<script>errorhandle_postform('抱歉，验证码填写错误', {});</script>]]></root>"#;
+        assert!(matches!(
+            parse_ajax_result(seccode),
+            Err(RSBBSError::SeccodeRequired)
+        ));
+        let escaped = r#"<root><![CDATA[<script>errorhandle_postform('抱歉，\'二手交易\' 版块禁止发表该类主题', {});</script>]]></root>"#;
+        assert!(matches!(
+            parse_ajax_result(escaped),
+            Err(RSBBSError::PermissionDenied(msg)) if msg == "抱歉，'二手交易' 版块禁止发表该类主题"
+        ));
+        let escaped = r#"<root><![CDATA[<script>succeedhandle_postform('forum.php?mod=viewthread&amp;tid=100&amp;extra=\'a\'&amp;pid=202', '回复发布成功', {});</script>]]></root>"#;
+        let reply = super::posted(&parse_ajax_result(escaped).unwrap(), None);
+        assert_eq!((reply.tid(), reply.pid()), (Some(100), Some(202)));
+        let guest = r#"<root><![CDATA[<script>errorhandle_postform('抱歉，您无权进行当前操作，这可能是因为您还没有登录', {});</script>]]></root>"#;
+        assert!(matches!(
+            parse_ajax_result(guest),
+            Err(RSBBSError::NotLoggedIn)
+        ));
+    }
+    #[test]
+    fn test_plain_ajax_message() {
+        let sent = r#"<root><![CDATA[操作成功 ]]></root>"#;
+        assert_eq!(parse_ajax_result(sent).unwrap(), "");
+        let sent = r#"<root><![CDATA[<div class="alert_right">消息发送成功</div>]]></root>"#;
+        assert_eq!(parse_ajax_result(sent).unwrap(), "");
+        let failed = r#"<root><![CDATA[<div class="alert_error">抱歉，发送不成功，请稍后再试</div>]]></root>"#;
+        assert!(matches!(
+            parse_ajax_result(failed),
+            Err(RSBBSError::ServerError(_))
+        ));
+        let failed = r#"<root><![CDATA[对方设置了隐私，消息未成功发送]]></root>"#;
+        assert!(matches!(
+            parse_ajax_result(failed),
+            Err(RSBBSError::ServerError(_))
+        ));
+        assert!(matches!(
+            parse_ajax_result("<root><![CDATA[]]></root>"),
+            Err(RSBBSError::ParseError(_))
+        ));
+    }
+    #[test]
+    fn test_posted_without_url() {
+        assert_eq!(
+            posted("", None),
+            Posted {
+                tid: None,
+                pid: None
+            }
+        );
+        assert_eq!(posted("", Some(100)).tid(), Some(100));
+    }
+
+    #[test]
+    fn test_hidden_inputs() {
+        let html = r#"<form method="post" id="postform" action="forum.php?mod=post"><input type="hidden" name="formhash" id="formhash" value="a1b2c3d4" />
+<input type="hidden" name="posttime" id="posttime" value="1700000000" /><input type="text" name="subject" value="" /></form>"#;
+        let inputs = hidden_inputs("postform", html).unwrap();
+        assert_eq!(
+            inputs,
+            [("formhash", "a1b2c3d4"), ("posttime", "1700000000")]
+        );
+        assert!(hidden_inputs("postform", "<form id=\"postform\"></form>").is_err());
+    }
+}
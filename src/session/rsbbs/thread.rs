@@ -0,0 +1,439 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    RSBBSSession,
+    error::RSBBSError,
+    session::rsbbs::{board_fid, check_discuz_page, first_anchor},
+    utils::rsbbs::{
+        decode_entities, find_attr, find_between, find_discuz_time, find_element_inner,
+        find_page_count, find_query_u64, first_u64, html_to_text, leading_u64,
+    },
+};
+use chrono::NaiveDateTime;
+use getset2::Getset2;
+
+/// 帖子中引用的回复。
+#[derive(Debug, Clone, PartialEq, Eq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct Quote {
+    /// 被引用帖子的 pid.
+    #[getset2(get_copy(pub), skip(get_ref))]
+    pid: Option<u64>,
+    author: Option<String>,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    time: Option<NaiveDateTime>,
+    text: String,
+}
+
+/// 帖子的附件。
+#[derive(Debug, Clone, PartialEq, Eq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct Attachment {
+    /// 附件标识，下载链接中的 `aid`, 可能是编码后的字符串。
+    id: String,
+    name: String,
+    /// 相对于论坛根目录的地址。
+    url: String,
+    /// 页面上显示的大小，如 `12.3 KB`.
+    size: Option<String>,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    downloads: Option<u64>,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    is_image: bool,
+}
+
+/// 主题中的一个帖子。
+#[derive(Debug, Clone, PartialEq, Eq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct Post {
+    #[getset2(get_copy(pub), skip(get_ref))]
+    pid: u64,
+    /// 楼层，楼主为 1.
+    #[getset2(get_copy(pub), skip(get_ref))]
+    floor: u32,
+    author: String,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    author_uid: Option<u64>,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    time: Option<NaiveDateTime>,
+    /// 帖子内容的原始 html.
+    html: String,
+    /// 帖子内容的纯文本，不含引用与编辑记录。
+    text: String,
+    quotes: Vec<Quote>,
+    attachments: Vec<Attachment>,
+    /// 编辑记录，如 `本帖最后由 xxx 于 2024-1-1 12:00 编辑`.
+    edit_note: Option<String>,
+}
+
+/// 主题的某一页。
+#[derive(Debug, Clone, PartialEq, Eq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct ThreadPage {
+    #[getset2(get_copy(pub), skip(get_ref))]
+    page: u32,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    total_pages: u32,
+    subject: String,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    fid: Option<u64>,
+    posts: Vec<Post>,
+}
+
+/// 完整的主题。
+#[derive(Debug, Clone, PartialEq, Eq, Getset2)]
+#[getset2(get_ref(pub))]
+pub struct Thread {
+    #[getset2(get_copy(pub), skip(get_ref))]
+    tid: u64,
+    subject: String,
+    #[getset2(get_copy(pub), skip(get_ref))]
+    fid: Option<u64>,
+    posts: Vec<Post>,
+}
+
+fn parse_floor(html: &str, pid: u64) -> Option<u32> {
+    let s = html.find(&format!("id=\"postnum{pid}\""))?;
+    let text = find_between(&html[s..], ">", "</a>")?;
+    if let Some(n) = find_between(text, "<em>", "</em>") {
+        return n.trim().parse().ok();
+    }
+    let text = html_to_text(text);
+    ["楼主", "沙发", "板凳", "地板"]
+        .iter()
+        .position(|name| text.contains(name))
+        .map(|i| i as u32 + 1)
+        .or_else(|| first_u64(&text).map(|n| n as u32))
+}
+
+fn parse_quote(html: &str) -> Quote {
+    let (header, body) = match html.find("</a>") {
+        Some(e) if html[..e].contains("goto=findpost") => (Some(&html[..e]), &html[e + 4..]),
+        _ => (None, html),
+    };
+    let pid = header.and_then(|h| find_query_u64(&decode_entities(h), "pid"));
+    let header = header.map(html_to_text).unwrap_or_default();
+    let (author, time) = match header.split_once("发表于") {
+        Some((author, time)) => (
+            Some(author.trim().to_owned()).filter(|a| !a.is_empty()),
+            find_discuz_time(time),
+        ),
+        None => (None, None),
+    };
+    Quote {
+        pid,
+        author,
+        time,
+        text: html_to_text(body),
+    }
+}
+
+/// 去除 `content` 中以 `begin` 开始、`tag` 结束的元素，并将其内容交给 `f`.
+fn take_elements(content: &str, begin: &str, tag: &str, mut f: impl FnMut(&str)) -> String {
+    let mut rest = content;
+    let mut out = String::with_capacity(content.len());
+    while let Some(s) = rest.find(begin) {
+        out.push_str(&rest[..s]);
+        let after = &rest[s..];
+        let Some(inner) = after
+            .find('>')
+            .and_then(|o| find_element_inner(&after[o + 1..], tag).map(|i| (o + 1, i)))
+        else {
+            rest = &rest[s + begin.len()..];
+            continue;
+        };
+        f(inner.1);
+        let end = inner.0 + inner.1.len();
+        rest = &after[end..];
+        rest = rest.find('>').map_or("", |e| &rest[e + 1..]);
+    }
+    out.push_str(rest);
+    out
+}
+
+fn parse_attachments(html: &str) -> Vec<Attachment> {
+    let mut attachments = Vec::new();
+    const LINK: &str = "mod=attachment&amp;aid=";
+    let mut offset = 0;
+    while let Some(s) = html[offset..].find(LINK) {
+        let s = offset + s;
+        offset = s + LINK.len();
+        let after = &html[offset..];
+        let segment = &after[..after.find(LINK).unwrap_or(after.len())];
+        let Some((href, name)) = html[..s]
+            .rfind("<a ")
+            .and_then(|a| first_anchor(&html[a..]))
+        else {
+            continue;
+        };
+        let name = html_to_text(name);
+        let id = find_between(&format!("{href}\""), "aid=", "\"")
+            .map(|id| id.split('&').next().unwrap_or(id).to_owned())
+            .unwrap_or_default();
+        if name.is_empty()
+            || name == "下载附件"
+            || attachments.iter().any(|a: &Attachment| a.id == id)
+        {
+            continue;
+        }
+        let (size, downloads) = match segment.find("下载次数") {
+            Some(d) => {
+                let before = html_to_text(&segment[..d]);
+                let size = before
+                    .lines()
+                    .last()
+                    .map(|l| {
+                        l.trim()
+                            .trim_start_matches('(')
+                            .trim_end_matches([',', '，', ' '])
+                    })
+                    .filter(|l| !l.is_empty())
+                    .map(str::to_owned);
+                (size, first_u64(&segment[d..]))
+            }
+            None => (None, None),
+        };
+        attachments.push(Attachment {
+            id,
+            name,
+            url: decode_entities(href),
+            size,
+            downloads,
+            is_image: false,
+        });
+    }
+    let mut rest = html;
+    while let Some(s) = rest.find("<img id=\"aimg_") {
+        rest = &rest[s..];
+        let tag = &rest[..rest.find('>').unwrap_or(rest.len())];
+        rest = &rest[tag.len()..];
+        let Some(url) = ["zoomfile", "file", "src"]
+            .iter()
+            .find_map(|attr| find_attr(tag, attr))
+        else {
+            continue;
+        };
+        let id = find_attr(tag, "aid")
+            .or_else(|| find_attr(tag, "id").and_then(|id| id.strip_prefix("aimg_")))
+            .unwrap_or_default()
+            .to_owned();
+        let name = find_attr(tag, "alt")
+            .or_else(|| find_attr(tag, "title"))
+            .map(decode_entities)
+            .unwrap_or_default();
+        attachments.push(Attachment {
+            id,
+            name,
+            url: decode_entities(url),
+            size: None,
+            downloads: None,
+            is_image: true,
+        });
+    }
+    attachments
+}
+
+fn parse_post(pid: u64, html: &str) -> Option<Post> {
+    let (author, author_uid) = match find_between(html, "class=\"authi\"", "</div>") {
+        Some(authi) => match first_anchor(authi) {
+            Some((href, name)) => (html_to_text(name), find_query_u64(href, "uid")),
+            None => (html_to_text(&authi[authi.find('>')? + 1..]), None),
+        },
+        None => (String::from("匿名"), None),
+    };
+    let time = html
+        .find(&format!("id=\"authorposton{pid}\""))
+        .and_then(|s| find_between(&html[s..], ">", "</em>"))
+        .and_then(find_discuz_time);
+    let message = html.find(&format!("id=\"postmessage_{pid}\""))?;
+    let message = &html[message..];
+    let content = find_element_inner(&message[message.find('>')? + 1..], "td")?;
+    let mut edit_note = None;
+    let text = take_elements(content, "<i class=\"pstatus\"", "i", |note| {
+        edit_note = Some(html_to_text(note));
+    });
+    let mut quotes = Vec::new();
+    let text = take_elements(&text, "<div class=\"quote\"", "div", |quote| {
+        let quote = find_between(quote, "<blockquote>", "</blockquote>").unwrap_or(quote);
+        quotes.push(parse_quote(quote));
+    });
+    Some(Post {
+        pid,
+        floor: parse_floor(html, pid).unwrap_or_default(),
+        author,
+        author_uid,
+        time,
+        html: content.trim().to_owned(),
+        text: html_to_text(&text),
+        quotes,
+        attachments: parse_attachments(html),
+        edit_note,
+    })
+}
+
+pub(super) fn parse_thread_page(html: &str, page: u32) -> Result<ThreadPage, RSBBSError> {
+    check_discuz_page(html)?;
+    let subject = find_between(html, "id=\"thread_subject\"", "</span>")
+        .and_then(|s| Some(html_to_text(&s[s.find('>')? + 1..])))
+        .ok_or_else(|| RSBBSError::ParseError("主题页面中没有标题。".to_owned()))?;
+    let fid = find_between(html, "id=\"pt\"", "</div>").and_then(|pt| {
+        pt.split("<a ")
+            .filter_map(|a| find_attr(a, "href").and_then(board_fid))
+            .last()
+    });
+    const POST: &str = "<div id=\"post_";
+    let mut starts = Vec::new();
+    let mut offset = 0;
+    while let Some(s) = html[offset..].find(POST) {
+        let s = offset + s;
+        offset = s + POST.len();
+        let id = &html[offset..];
+        if let Some(pid) = leading_u64(id).filter(|_| {
+            id.trim_start_matches(|c: char| c.is_ascii_digit())
+                .starts_with('"')
+        }) {
+            starts.push((s, pid));
+        }
+    }
+    let mut posts: Vec<Post> = Vec::with_capacity(starts.len());
+    for (i, &(s, pid)) in starts.iter().enumerate() {
+        let end = starts.get(i + 1).map_or(html.len(), |&(e, _)| e);
+        if let Some(mut post) = parse_post(pid, &html[s..end]) {
+            if post.floor == 0 {
+                post.floor = posts.last().map_or(1, |p| p.floor + 1);
+            }
+            posts.push(post);
+        }
+    }
+    Ok(ThreadPage {
+        page,
+        total_pages: find_page_count(html),
+        subject,
+        fid,
+        posts,
+    })
+}
+
+impl RSBBSSession {
+    /// 获取主题的某一页，`page` 从 1 开始。
+    pub fn thread_page(&self, tid: u64, page: u32) -> Result<ThreadPage, RSBBSError> {
+        let html = crate::protocol::rsbbs::view_thread(self, tid, page)?
+            .into_body()
+            .read_to_string()?;
+        parse_thread_page(&html, page)
+    }
+    /// 获取主题的所有帖子。
+    pub fn thread(&self, tid: u64) -> Result<Thread, RSBBSError> {
+        let first = self.thread_page(tid, 1)?;
+        let ThreadPage {
+            total_pages,
+            subject,
+            fid,
+            mut posts,
+            ..
+        } = first;
+        for page in 2..=total_pages {
+            posts.extend(self.thread_page(tid, page)?.posts);
+        }
+        Ok(Thread {
+            tid,
+            subject,
+            fid,
+            posts,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THREAD: &str = r##"
+<div id="pt" class="bm cl"><div class="z"><a href="./" class="nvhm">首页</a><em>&rsaquo;</em><a href="forum.php?gid=1">校园生活</a><em>&rsaquo;</em><a href="forum.php?mod=forumdisplay&amp;fid=72">二手市场</a></div></div>
+<h1 class="ts"><span id="thread_subject">出 自行车 &amp; 头盔</span></h1>
+<div id="post_200"><table id="pid200" class="plhin"><tr><td class="pls">
+<div class="pi"><div class="authi"><a href="home.php?mod=space&amp;uid=456" target="_blank" class="xw1">seller</a></div></div></td>
+<td class="plc"><div class="pi"><strong><a href="forum.php?mod=viewthread&amp;tid=100" id="postnum200">楼主</a></strong>
+<div class="authi"><em id="authorposton200">发表于 <span title="2024-3-5 08:07">3&nbsp;天前</span></em></div></div>
+<div class="pct"><div class="pcb"><div class="t_fsz"><table cellspacing="0" cellpadding="0"><tr><td class="t_f" id="postmessage_200">
+<i class="pstatus"> 本帖最后由 seller 于 2024-3-5 09:00 编辑 </i><br />
+九成新<br /><table><tr><td>价格</td><td>200</td></tr></table>
+</td></tr></table>
+<div class="pattl"><ignore_js_op><dl class="tattl"><dd><p class="attnm"><a href="forum.php?mod=attachment&amp;aid=MTIzfGFi" target="_blank">发票.pdf</a></p><p>12.3 KB, 下载次数: 4</p></dd></dl></ignore_js_op>
+<ignore_js_op><img id="aimg_124" aid="124" src="static/image/common/none.gif" zoomfile="data/attachment/forum/a.jpg" file="data/attachment/forum/a.jpg" alt="a.jpg" /></ignore_js_op></div>
+</div></div></div></td></tr></table></div>
+<div id="post_rate_div_200"></div>
+<div id="post_201"><table id="pid201" class="plhin"><tr><td class="pls">
+<div class="pi"><div class="authi"><a href="home.php?mod=space&amp;uid=789" class="xw1">buyer</a></div></div></td>
+<td class="plc"><div class="pi"><strong><a href="#" id="postnum201"><em>5</em><sup>#</sup></a></strong>
+<div class="authi"><em id="authorposton201">发表于 2024-3-6 10:00:01</em></div></div>
+<table><tr><td class="t_f" id="postmessage_201">
+<div class="quote"><blockquote><font size="2"><a href="forum.php?mod=redirect&amp;goto=findpost&amp;pid=200&amp;ptid=100" target="_blank"><font color="#999999">seller 发表于 2024-3-5 08:07</font></a></font><br />
+九成新</blockquote></div><br />
+还在吗？
+</td></tr></table></td></tr></table></div>
+<div class="pg"><label><span title="共 2 页"> / 2 页</span></label></div>"##;
+
+    #[test]
+    fn test_parse_thread_page() {
+        let page = parse_thread_page(THREAD, 1).unwrap();
+        assert_eq!(page.subject(), "出 自行车 & 头盔");
+        assert_eq!((page.fid(), page.total_pages()), (Some(72), 2));
+        let [first, reply] = &page.posts()[..] else {
+            panic!("{:?}", page.posts());
+        };
+        assert_eq!((first.pid(), first.floor()), (200, 1));
+        assert_eq!(
+            (first.author().as_str(), first.author_uid()),
+            ("seller", Some(456))
+        );
+        assert!(first.time().is_some());
+        assert_eq!(
+            first.edit_note().as_deref(),
+            Some("本帖最后由 seller 于 2024-3-5 09:00 编辑")
+        );
+        assert_eq!(first.text(), "九成新\n价格\t200");
+        assert!(first.html().contains("<table>"));
+        let [pdf, img] = &first.attachments()[..] else {
+            panic!("{:?}", first.attachments());
+        };
+        assert_eq!(
+            (pdf.id().as_str(), pdf.name().as_str()),
+            ("MTIzfGFi", "发票.pdf")
+        );
+        assert_eq!(pdf.url(), "forum.php?mod=attachment&aid=MTIzfGFi");
+        assert_eq!(
+            (pdf.size().as_deref(), pdf.downloads()),
+            (Some("12.3 KB"), Some(4))
+        );
+        assert!(img.is_image() && img.url() == "data/attachment/forum/a.jpg");
+        assert_eq!((reply.floor(), reply.text().as_str()), (5, "还在吗？"));
+        let quote = &reply.quotes()[0];
+        assert_eq!(
+            (quote.pid(), quote.author().as_deref()),
+            (Some(200), Some("seller"))
+        );
+        assert!(quote.time().is_some());
+        assert_eq!(quote.text(), "九成新");
+    }
+}
@@ -107,7 +107,7 @@ pub fn aes_dec(
 }
 
 #[inline]
-pub fn percent_enc(input: &str) -> PercentEncode {
+pub fn percent_enc(input: &str) -> PercentEncode<'_> {
     percent_encoding::utf8_percent_encode(input, percent_encoding::NON_ALPHANUMERIC)
 }
 #[inline]
@@ -124,7 +124,8 @@ pub fn md5_enc<T: AsRef<[u8]>>(input: T) -> [u8; 16] {
     md5::compute(input).0
 }
 #[inline]
-pub fn flatten_bytes<const BLOCK_SIZE: usize>(mut blocks: Vec<[u8; BLOCK_SIZE]>) -> Vec<u8> {
+pub fn flatten_bytes<const BLOCK_SIZE: usize>(blocks: Vec<[u8; BLOCK_SIZE]>) -> Vec<u8> {
+    let mut blocks = std::mem::ManuallyDrop::new(blocks);
     let (p, l, c) = (blocks.as_mut_ptr(), blocks.len(), blocks.capacity());
     unsafe { Vec::from_raw_parts(p as *mut u8, l * BLOCK_SIZE, c * BLOCK_SIZE) }
 }
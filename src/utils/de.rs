@@ -0,0 +1,159 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! 宽松的反序列化辅助函数。
+//!
+//! 后端返回的字段类型并不稳定，数字时常以字符串给出，空值则可能是 `null` 或 `""`.
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+#[cfg(feature = "ehall")]
+fn to_f64(v: &Value) -> Option<f64> {
+    match v {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+#[cfg(feature = "ehall")]
+pub fn u32_lenient<'de, D: Deserializer<'de>>(d: D) -> Result<u32, D::Error> {
+    Ok(to_f64(&Value::deserialize(d)?).map_or(0, |n| n as u32))
+}
+#[cfg(feature = "ehall")]
+pub fn opt_u32_lenient<'de, D: Deserializer<'de>>(d: D) -> Result<Option<u32>, D::Error> {
+    Ok(to_f64(&Value::deserialize(d)?).map(|n| n as u32))
+}
+#[cfg(feature = "ehall")]
+pub fn opt_f64_lenient<'de, D: Deserializer<'de>>(d: D) -> Result<Option<f64>, D::Error> {
+    Ok(to_f64(&Value::deserialize(d)?))
+}
+#[cfg(feature = "ehall")]
+pub fn f64_lenient<'de, D: Deserializer<'de>>(d: D) -> Result<f64, D::Error> {
+    opt_f64_lenient(d).map(|n| n.unwrap_or_default())
+}
+#[cfg(feature = "ehall")]
+/// 接受 `2021-09-01`, `2021-09-01 00:00:00`, `2021-09`, `202109` 等形式，仅有年月时取当月 1 日。
+pub fn opt_date_lenient<'de, D: Deserializer<'de>>(
+    d: D,
+) -> Result<Option<chrono::NaiveDate>, D::Error> {
+    let s = opt_string(d)?.unwrap_or_default();
+    let digits = s
+        .chars()
+        .filter(char::is_ascii_digit)
+        .take(8)
+        .collect::<String>();
+    let ymd = match digits.len() {
+        8 => digits,
+        6 => format!("{digits}01"),
+        _ => return Ok(None),
+    };
+    Ok(chrono::NaiveDate::parse_from_str(&ymd, "%Y%m%d").ok())
+}
+#[cfg(feature = "ehall")]
+/// 接受 `2024-10-01 12:00:00`, `2024-10-01 12:00` 与毫秒时间戳。
+pub fn opt_datetime_lenient<'de, D: Deserializer<'de>>(
+    d: D,
+) -> Result<Option<chrono::NaiveDateTime>, D::Error> {
+    use chrono::{DateTime, NaiveDateTime};
+    Ok(match Value::deserialize(d)? {
+        Value::Number(n) => n
+            .as_i64()
+            .and_then(DateTime::from_timestamp_millis)
+            .map(|t| {
+                t.with_timezone(
+                    &chrono::FixedOffset::east_opt(crate::utils::IcsCalendar::UTC_OFFSET_SECS)
+                        .expect("valid offset"),
+                )
+                .naive_local()
+            }),
+        Value::String(s) => ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"]
+            .iter()
+            .find_map(|f| NaiveDateTime::parse_from_str(s.trim(), f).ok()),
+        _ => None,
+    })
+}
+#[cfg(feature = "payment")]
+/// 金额，以分为单位。接受数字与 `"1200.00"` 形式的字符串，无法解析或精度超过分时报错。
+pub fn amount_fen<'de, D: Deserializer<'de>>(d: D) -> Result<i64, D::Error> {
+    let v = Value::deserialize(d)?;
+    let s = match &v {
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => s.trim().to_owned(),
+        _ => String::new(),
+    };
+    parse_fen(&s).ok_or_else(|| serde::de::Error::custom(format!("金额无法解析：`{v}`.")))
+}
+#[cfg(feature = "payment")]
+fn parse_fen(s: &str) -> Option<i64> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s),
+    };
+    let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+    if int.is_empty() || !int.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    if !frac.bytes().all(|b| b.is_ascii_digit()) || frac.bytes().skip(2).any(|b| b != b'0') {
+        return None;
+    }
+    let cents = format!("{:0<2}", &frac[..frac.len().min(2)]);
+    let fen = int.parse::<i64>().ok()?.checked_mul(100)? + cents.parse::<i64>().ok()?;
+    Some(if negative { -fen } else { fen })
+}
+#[cfg(feature = "ehall")]
+/// `"1"`, `"是"`, `1`, `true` 均视为真。
+pub fn bool_lenient<'de, D: Deserializer<'de>>(d: D) -> Result<bool, D::Error> {
+    Ok(match Value::deserialize(d)? {
+        Value::Bool(b) => b,
+        Value::Number(n) => n.as_f64().is_some_and(|n| n != 0.0),
+        Value::String(s) => matches!(s.trim(), "1" | "是" | "true" | "Y"),
+        _ => false,
+    })
+}
+pub fn opt_string<'de, D: Deserializer<'de>>(d: D) -> Result<Option<String>, D::Error> {
+    Ok(match Value::deserialize(d)? {
+        Value::String(s) if !s.trim().is_empty() => Some(s.trim().to_owned()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    })
+}
+#[cfg(feature = "ehall")]
+pub fn string_lenient<'de, D: Deserializer<'de>>(d: D) -> Result<String, D::Error> {
+    opt_string(d).map(Option::unwrap_or_default)
+}
+
+#[cfg(all(test, feature = "payment"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_fen() {
+        assert_eq!(parse_fen("1200.00"), Some(120000));
+        assert_eq!(parse_fen("5000"), Some(500000));
+        assert_eq!(parse_fen("0.5"), Some(50));
+        assert_eq!(parse_fen("-3.07"), Some(-307));
+        assert_eq!(parse_fen("1.230"), Some(123));
+        assert_eq!(parse_fen("1.234"), None);
+        assert_eq!(parse_fen(""), None);
+        assert_eq!(parse_fen("未知"), None);
+    }
+}
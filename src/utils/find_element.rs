@@ -83,8 +83,221 @@ pub fn find_id_value_pair<'a>(
 pub(crate) mod rsbbs {
     use std::ops::Range;
 
+    use chrono::NaiveDateTime;
+
     use crate::error::{CaptchaError, LoginError};
 
+    /// 找到 `begin` 与其后第一个 `end` 之间的内容。
+    #[inline]
+    pub fn find_between<'a>(html: &'a str, begin: &str, end: &str) -> Option<&'a str> {
+        let html = &html[html.find(begin)? + begin.len()..];
+        Some(&html[..html.find(end)?])
+    }
+    /// 找到标签中的属性值，如 `find_attr(r#"<a href="x">"#, "href")` 得到 `x`.
+    #[inline]
+    pub fn find_attr<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+        let tag = &tag[..tag.find('>').unwrap_or(tag.len())];
+        let mut rest = tag;
+        loop {
+            let s = rest.find(name)?;
+            let before = rest[..s].chars().next_back();
+            rest = &rest[s + name.len()..];
+            if before.is_none_or(char::is_whitespace) && rest.starts_with("=\"") {
+                rest = &rest[2..];
+                return Some(&rest[..rest.find('"')?]);
+            }
+        }
+    }
+    /// 找到链接中的数字参数，兼容 `&amp;` 与伪静态地址，如 `fid=2` 或 `forum-2-1.html`.
+    pub fn find_query_u64(url: &str, key: &str) -> Option<u64> {
+        let mut rest = url;
+        while let Some(s) = rest.find(key) {
+            let before = rest[..s].chars().next_back();
+            rest = &rest[s + key.len()..];
+            if matches!(before, None | Some('?' | '&' | ';')) && rest.starts_with('=') {
+                return leading_u64(&rest[1..]);
+            }
+        }
+        None
+    }
+    /// 字符串开头的数字。
+    #[inline]
+    pub fn leading_u64(s: &str) -> Option<u64> {
+        let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        s[..end].parse().ok()
+    }
+    /// 字符串中第一段数字，忽略千分位逗号。
+    #[inline]
+    pub fn first_u64(s: &str) -> Option<u64> {
+        let s = &s[s.find(|c: char| c.is_ascii_digit())?..];
+        let end = s
+            .find(|c: char| !c.is_ascii_digit() && c != ',')
+            .unwrap_or(s.len());
+        s[..end].replace(',', "").parse().ok()
+    }
+    /// 找到元素的内容，`html` 从 `<tag ...>` 之后开始，会跳过嵌套的同名元素。
+    pub fn find_element_inner<'a>(html: &'a str, tag: &str) -> Option<&'a str> {
+        let open = format!("<{tag}");
+        let close = format!("</{tag}");
+        let mut depth = 1usize;
+        let mut offset = 0;
+        loop {
+            let rest = &html[offset..];
+            let c = find_tag(rest, &close)?;
+            match find_tag(rest, &open).filter(|o| *o < c) {
+                Some(o) => {
+                    depth += 1;
+                    offset += o + open.len();
+                }
+                None => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(&html[..offset + c]);
+                    }
+                    offset += c + close.len();
+                }
+            }
+        }
+    }
+    /// 查找标签的开头，要求标签名完整匹配，即 `<i` 不会匹配 `<img`.
+    fn find_tag(html: &str, prefix: &str) -> Option<usize> {
+        let mut offset = 0;
+        loop {
+            let i = offset + html[offset..].find(prefix)?;
+            let next = html.as_bytes().get(i + prefix.len());
+            if next.is_none_or(|b| b.is_ascii_whitespace() || matches!(b, b'>' | b'/')) {
+                return Some(i);
+            }
+            offset = i + prefix.len();
+        }
+    }
+    /// 解码常见的 html 实体。
+    pub fn decode_entities(s: &str) -> String {
+        let mut out = String::with_capacity(s.len());
+        let mut rest = s;
+        while let Some(i) = rest.find('&') {
+            out.push_str(&rest[..i]);
+            rest = &rest[i..];
+            let decoded = rest.find(';').filter(|e| *e <= 10).and_then(|e| {
+                let c = match &rest[1..e] {
+                    "amp" => '&',
+                    "lt" => '<',
+                    "gt" => '>',
+                    "quot" => '"',
+                    "apos" => '\'',
+                    "nbsp" => ' ',
+                    n => match n.strip_prefix("#x").or_else(|| n.strip_prefix("#X")) {
+                        Some(hex) => char::from_u32(u32::from_str_radix(hex, 16).ok()?)?,
+                        None => char::from_u32(n.strip_prefix('#')?.parse().ok()?)?,
+                    },
+                };
+                Some((c, e))
+            });
+            match decoded {
+                Some((c, e)) => {
+                    out.push(c);
+                    rest = &rest[e + 1..];
+                }
+                None => {
+                    out.push('&');
+                    rest = &rest[1..];
+                }
+            }
+        }
+        out.push_str(rest);
+        out
+    }
+    /// 将 html 片段转为纯文本，`<br>` 与块级元素转为换行，并去除 `<script>` 与 `<style>`.
+    pub fn html_to_text(html: &str) -> String {
+        let mut out = String::with_capacity(html.len());
+        let mut rest = html;
+        while let Some(s) = rest.find('<') {
+            // 源码中的换行只是空白，换行由 `<br>` 与块级元素决定。
+            out.extend(rest[..s].chars().map(|c| if c == '\n' { ' ' } else { c }));
+            rest = &rest[s..];
+            let Some(e) = rest.find('>') else {
+                break;
+            };
+            let closing = rest[1..e].starts_with('/');
+            let tag = rest[1..e].trim_start_matches('/').to_ascii_lowercase();
+            let name = tag
+                .split(|c: char| c.is_whitespace() || c == '/')
+                .next()
+                .unwrap_or_default();
+            rest = &rest[e + 1..];
+            match name {
+                "script" | "style" => {
+                    let close = format!("</{name}");
+                    rest = rest
+                        .to_ascii_lowercase()
+                        .find(&close)
+                        .and_then(|c| rest[c..].find('>').map(|e| &rest[c + e + 1..]))
+                        .unwrap_or_default();
+                }
+                "br" => out.push('\n'),
+                // 单元格之间以制表符分隔，行末的制表符会随空白一同去除。
+                "td" | "th" if closing => out.push('\t'),
+                "p" | "div" | "li" | "tr" | "blockquote"
+                    if !out.trim_end_matches(' ').ends_with('\n') =>
+                {
+                    out.push('\n')
+                }
+                _ => {}
+            }
+        }
+        out.extend(rest.chars().map(|c| if c == '\n' { ' ' } else { c }));
+        let text = decode_entities(&out);
+        let mut lines = Vec::new();
+        for line in text.lines().map(str::trim) {
+            if !line.is_empty() || lines.last().is_some_and(|l: &&str| !l.is_empty()) {
+                lines.push(line);
+            }
+        }
+        while lines.last().is_some_and(|l| l.is_empty()) {
+            lines.pop();
+        }
+        lines.join("\n")
+    }
+    /// 解析 Discuz 的时间，如 `2024-1-1 12:00`.
+    pub fn parse_discuz_time(s: &str) -> Option<NaiveDateTime> {
+        let s = decode_entities(s);
+        let s = s.trim();
+        ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"]
+            .iter()
+            .find_map(|f| NaiveDateTime::parse_from_str(s, f).ok())
+            .or_else(|| {
+                chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d")
+                    .ok()
+                    .and_then(|d| d.and_hms_opt(0, 0, 0))
+            })
+    }
+    /// 找到片段中的时间，优先使用 `<span title="...">` 中的绝对时间（页面上可能显示为“3 天前”）。
+    pub fn find_discuz_time(html: &str) -> Option<NaiveDateTime> {
+        if let Some(t) = find_between(html, "<span title=\"", "\"").and_then(parse_discuz_time) {
+            return Some(t);
+        }
+        let text = html_to_text(html);
+        text.find(|c: char| c.is_ascii_digit()).and_then(|s| {
+            let text = &text[s..];
+            let e = text
+                .find(|c: char| !(c.is_ascii_digit() || matches!(c, '-' | ':' | ' ')))
+                .unwrap_or(text.len());
+            parse_discuz_time(&text[..e])
+        })
+    }
+    /// Discuz 的提示页面（`showmessage`）中的信息。
+    pub fn find_discuz_message(html: &str) -> Option<String> {
+        let s = html.find("id=\"messagetext\"")?;
+        let msg = find_between(&html[s..], "<p>", "</p>")?;
+        Some(html_to_text(msg))
+    }
+    /// 页面中 `共 N 页` 的页数，没有分页时为 1.
+    pub fn find_page_count(html: &str) -> u32 {
+        find_between(html, "title=\"共 ", " 页\"")
+            .and_then(|n| n.trim().parse().ok())
+            .unwrap_or(1)
+    }
+
     /// 查找 `id_hash`, 该值与验证码相关。
     ///
     /// updateseccode('id_hash') 会更新二维码，所以该函数会找该函数，并提取其参数。
@@ -146,3 +359,64 @@ pub(crate) mod rsbbs {
             .ok_or_else(|| LoginError::ServerError("未找到登录地址。".to_owned()))
     }
 }
+
+#[cfg(all(test, feature = "rsbbs"))]
+mod tests {
+    use super::rsbbs::*;
+
+    #[test]
+    fn test_html_helpers() {
+        assert_eq!(
+            find_attr(
+                r#"<a class="xi2" href="forum.php?mod=forumdisplay&amp;fid=72">"#,
+                "href"
+            ),
+            Some("forum.php?mod=forumdisplay&amp;fid=72")
+        );
+        assert_eq!(find_attr(r#"<img data-src="a" src="b">"#, "src"), Some("b"));
+        assert_eq!(
+            find_query_u64("forum.php?mod=forumdisplay&amp;fid=72", "fid"),
+            Some(72)
+        );
+        assert_eq!(
+            find_query_u64("forum.php?mod=viewthread&ptid=3&tid=5", "tid"),
+            Some(5)
+        );
+        assert_eq!(first_u64(" / 12,345"), Some(12345));
+        assert_eq!(
+            find_element_inner("a<td>b</td>c</td>d", "td"),
+            Some("a<td>b</td>c")
+        );
+        assert_eq!(
+            find_element_inner(r#"<img src="a"><ins>b</ins><i>c</i>d</i>e"#, "i"),
+            Some(r#"<img src="a"><ins>b</ins><i>c</i>d"#)
+        );
+        assert_eq!(decode_entities("a&amp;b&#39;&#x4e2d;&bad"), "a&b'中&bad");
+        assert_eq!(
+            html_to_text("<p>第一行<br />第二行</p><script>x()</script>&nbsp;<b>粗</b>"),
+            "第一行\n第二行\n粗"
+        );
+        assert_eq!(
+            html_to_text(
+                "<table><tr><th>名称</th><th>价格</th></tr><tr><td>书</td><td>20</td></tr></table>"
+            ),
+            "名称\t价格\n书\t20"
+        );
+        assert_eq!(
+            find_discuz_time(r#"<span title="2024-3-5 08:07">3&nbsp;天前</span>"#),
+            parse_discuz_time("2024-03-05 08:07")
+        );
+        assert!(find_discuz_time("<em>发表于 2024-3-5 08:07:09</em>").is_some());
+        assert_eq!(
+            find_page_count(r#"<span title="共 12 页"> / 12 页</span>"#),
+            12
+        );
+        assert_eq!(
+            find_discuz_message(
+                r#"<div id="messagetext" class="alert_error"><p>抱歉，您尚未登录</p></div>"#
+            )
+            .as_deref(),
+            Some("抱歉，您尚未登录")
+        );
+    }
+}
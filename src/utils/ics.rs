@@ -0,0 +1,172 @@
+// MIT License
+//
+// Copyright (c) 2025 2025  learturely <learturely@gmail.com>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use std::fmt::{Display, Write};
+
+/// 日历中的一个事件，时间为北京时间。
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct IcsEvent {
+    /// 需保持稳定，重复导入时日历应用据此更新而非新增事件。
+    pub uid: String,
+    pub summary: String,
+    pub location: Option<String>,
+    pub description: Option<String>,
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+}
+
+/// RFC 5545 日历。
+#[derive(Debug, Clone)]
+pub struct IcsCalendar {
+    name: String,
+    events: Vec<IcsEvent>,
+    stamp: DateTime<Utc>,
+}
+impl IcsCalendar {
+    pub const TZID: &'static str = "Asia/Shanghai";
+    /// [`Self::TZID`] 相对 UTC 的偏移（秒），该时区没有夏令时。
+    pub const UTC_OFFSET_SECS: i32 = 8 * 3600;
+    #[inline]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            events: Vec::new(),
+            stamp: Utc::now(),
+        }
+    }
+    #[inline]
+    pub fn push(&mut self, event: IcsEvent) {
+        self.events.push(event);
+    }
+    #[inline]
+    pub fn events(&self) -> &[IcsEvent] {
+        &self.events
+    }
+}
+impl Extend<IcsEvent> for IcsCalendar {
+    #[inline]
+    fn extend<T: IntoIterator<Item = IcsEvent>>(&mut self, iter: T) {
+        self.events.extend(iter);
+    }
+}
+
+/// 转义 TEXT 类型的值。
+fn escape_text(text: &str) -> String {
+    let mut r = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => r.push_str("\\\\"),
+            ';' => r.push_str("\\;"),
+            ',' => r.push_str("\\,"),
+            '\n' => r.push_str("\\n"),
+            '\r' => {}
+            c => r.push(c),
+        }
+    }
+    r
+}
+/// 按 75 字节折行，不截断 UTF-8 字符。
+fn write_folded(f: &mut impl Write, line: &str) -> std::fmt::Result {
+    let mut len = 0;
+    for c in line.chars() {
+        if len + c.len_utf8() > 75 {
+            f.write_str("\r\n ")?;
+            len = 1;
+        }
+        f.write_char(c)?;
+        len += c.len_utf8();
+    }
+    f.write_str("\r\n")
+}
+impl Display for IcsCalendar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const LOCAL: &str = "%Y%m%dT%H%M%S";
+        let tzid = Self::TZID;
+        let stamp = self.stamp.format("%Y%m%dT%H%M%SZ");
+        for line in [
+            "BEGIN:VCALENDAR",
+            "VERSION:2.0",
+            "PRODID:-//learturely//x_l4rs//CN",
+            "CALSCALE:GREGORIAN",
+            "METHOD:PUBLISH",
+        ] {
+            write_folded(f, line)?;
+        }
+        write_folded(f, &format!("X-WR-CALNAME:{}", escape_text(&self.name)))?;
+        write_folded(f, &format!("X-WR-TIMEZONE:{tzid}"))?;
+        for line in [
+            "BEGIN:VTIMEZONE",
+            &format!("TZID:{tzid}"),
+            "BEGIN:STANDARD",
+            "DTSTART:19700101T000000",
+            "TZOFFSETFROM:+0800",
+            "TZOFFSETTO:+0800",
+            "TZNAME:CST",
+            "END:STANDARD",
+            "END:VTIMEZONE",
+        ] {
+            write_folded(f, line)?;
+        }
+        for e in &self.events {
+            write_folded(f, "BEGIN:VEVENT")?;
+            write_folded(f, &format!("UID:{}", e.uid))?;
+            write_folded(f, &format!("DTSTAMP:{stamp}"))?;
+            write_folded(f, &format!("DTSTART;TZID={tzid}:{}", e.start.format(LOCAL)))?;
+            write_folded(f, &format!("DTEND;TZID={tzid}:{}", e.end.format(LOCAL)))?;
+            write_folded(f, &format!("SUMMARY:{}", escape_text(&e.summary)))?;
+            if let Some(location) = &e.location {
+                write_folded(f, &format!("LOCATION:{}", escape_text(location)))?;
+            }
+            if let Some(description) = &e.description {
+                write_folded(f, &format!("DESCRIPTION:{}", escape_text(description)))?;
+            }
+            write_folded(f, "END:VEVENT")?;
+        }
+        write_folded(f, "END:VCALENDAR")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_ics() {
+        let day = NaiveDate::from_ymd_opt(2024, 9, 2).unwrap();
+        let mut calendar = IcsCalendar::new("课表");
+        calendar.push(IcsEvent {
+            uid: "a@x_l4rs".to_owned(),
+            summary: "高等数学; 习题课".to_owned(),
+            location: Some("B-101".to_owned()),
+            description: Some("张三".repeat(30)),
+            start: day.and_hms_opt(8, 30, 0).unwrap(),
+            end: day.and_hms_opt(10, 5, 0).unwrap(),
+        });
+        let ics = calendar.to_string();
+        assert!(ics.contains("DTSTART;TZID=Asia/Shanghai:20240902T083000\r\n"));
+        assert!(ics.contains("SUMMARY:高等数学\\; 习题课\r\n"));
+        assert!(ics.lines().all(|l| l.len() <= 75));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    }
+}
@@ -21,11 +21,17 @@
 // SOFTWARE.
 
 mod cry;
+#[cfg(feature = "serde_json")]
+pub(crate) mod de;
 mod find_element;
+#[cfg(feature = "chrono")]
+mod ics;
 mod imageproc;
 
 pub use cry::*;
 pub(crate) use find_element::*;
+#[cfg(feature = "chrono")]
+pub use ics::*;
 pub(crate) use imageproc::*;
 
 use ureq::Agent;